@@ -1,9 +1,12 @@
 use crate::AbsoluteDirection;
 use crate::AxisCount;
+use crate::Connectivity;
 use crate::Coordinate;
 use crate::Mover;
 use crate::OutOfBoundsError;
 use crate::Positioned;
+use crate::ScreenCoordinate;
+use crate::WrapMode;
 
 #[allow(private_bounds)]
 pub trait Bounded: BoundSeal {
@@ -228,6 +231,84 @@ pub trait Bounded: BoundSeal {
         self.to_grid_like([x_matrix_like as u32, y_matrix_like as u32])
     }
 
+    /// The column and row of a coordinate, counted from the northwest corner with rows growing
+    /// south.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tudi::Bounded;
+    /// use tudi::Bounds;
+    /// use tudi::Coordinate;
+    /// use tudi::ScreenCoordinate;
+    /// let bounds = Bounds::from_boundaries(-2, 2, -1, 1);
+    ///
+    /// let screen = bounds.coordinate_to_screen(&Coordinate { x: -1, y: -1 }).unwrap();
+    /// assert_eq!(screen, ScreenCoordinate { column: 1, row: 2 });
+    /// ```
+    fn coordinate_to_screen<C: Positioned>(
+        &self,
+        coordinate: &C,
+    ) -> Result<ScreenCoordinate, OutOfBoundsError> {
+        if let Some((first_direction, second_direction)) = self.out_of_bounds_directions(coordinate)
+        {
+            Err(OutOfBoundsError::new(
+                *coordinate.position(),
+                first_direction,
+                second_direction,
+            ))
+        } else {
+            let [column, row] = self.to_matrix_like(coordinate.position());
+            Ok(ScreenCoordinate { row, column })
+        }
+    }
+
+    /// The coordinate at a column and row, counted from the northwest corner with rows growing
+    /// south.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column or row is past the bounds.
+    ///
+    /// See also [`Self::coordinate_to_screen`]
+    fn screen_to_coordinate(
+        &self,
+        screen: ScreenCoordinate,
+    ) -> Result<Coordinate, OutOfBoundsError> {
+        let x = i64::from(self.x_min_boundary()) + i64::from(screen.column);
+        let y = i64::from(self.y_max_boundary()) - i64::from(screen.row);
+        let east = x > i64::from(self.x_max_boundary());
+        let south = y < i64::from(self.y_min_boundary());
+        // Past the bounds, the position saturates at the limits of i32.
+        let position = Coordinate {
+            x: x.min(i64::from(i32::MAX)) as i32,
+            y: y.max(i64::from(i32::MIN)) as i32,
+        };
+
+        match (south, east) {
+            (false, false) => Ok(position),
+            (true, false) => Err(OutOfBoundsError::new(
+                position,
+                AbsoluteDirection::South,
+                None,
+            )),
+            (false, true) => Err(OutOfBoundsError::new(
+                position,
+                AbsoluteDirection::East,
+                None,
+            )),
+            (true, true) => Err(OutOfBoundsError::new(
+                position,
+                AbsoluteDirection::South,
+                Some(AbsoluteDirection::East),
+            )),
+        }
+    }
+
     /// returns true if the object is currently on its border.
     fn is_on_border(&self) -> bool
     where
@@ -278,7 +359,7 @@ pub trait Bounded: BoundSeal {
         let y: Option<i32> = self
             .y_max_boundary()
             .checked_sub_unsigned(distance[1])
-            .filter(|y| *y >= i32::MIN + 1)
+            .filter(|y| *y > i32::MIN)
             .filter(|y| *y >= self.y_min_boundary());
 
         match (x, y) {
@@ -365,10 +446,7 @@ pub trait Bounded: BoundSeal {
         &self,
         coordinate: C,
     ) -> impl Iterator<Item = Coordinate> {
-        coordinate
-            .euclid_neighbors()
-            .into_iter()
-            .filter(|x| self.is_within_bounds(x))
+        Connectivity::Euclid.bounded_neighbors(self, coordinate)
     }
 
     /// Returns true if the coordinate actually moved and false if not, if there is
@@ -394,6 +472,88 @@ pub trait Bounded: BoundSeal {
         self.set_coordinate(&Coordinate { x, y });
         previous_coordinate != *self.position()
     }
+
+    /// Same as [`Bounded::move_in_absolute_direction`] if the wrap mode is [`WrapMode::Bounded`].
+    /// With [`WrapMode::Toroidal`], a move past the border continues from the opposite border
+    /// instead. See [`Bounded::wrap`].
+    ///
+    /// Returns true if the position changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::{AbsoluteDirection, Bounded, BoundedMovingObject, Bounds, Coordinate, Positioned, WrapMode};
+    /// let bounds = Bounds::from_boundaries(-2, 2, -2, 2);
+    /// let mut marker = BoundedMovingObject::try_from((&bounds, &Coordinate { x: 1, y: 0 })).unwrap();
+    ///
+    /// marker.move_in_absolute_direction_with(AbsoluteDirection::East, 3, WrapMode::Toroidal);
+    /// assert_eq!(marker.position(), &Coordinate { x: -1, y: 0 });
+    ///
+    /// marker.move_in_absolute_direction_with(AbsoluteDirection::West, 3, WrapMode::Bounded);
+    /// assert_eq!(marker.position(), &Coordinate { x: -2, y: 0 });
+    /// ```
+    fn move_in_absolute_direction_with(
+        &mut self,
+        direction: AbsoluteDirection,
+        magnitude: u32,
+        wrap_mode: WrapMode,
+    ) -> bool
+    where
+        Self: Mover,
+    {
+        if wrap_mode == WrapMode::Bounded {
+            return self.move_in_absolute_direction(direction, magnitude);
+        }
+
+        let previous_coordinate = *self.position();
+        let magnitude = i64::from(magnitude);
+        let x = i64::from(previous_coordinate.x);
+        let y = i64::from(previous_coordinate.y);
+        let (x, y) = match direction {
+            AbsoluteDirection::North => (x, y + magnitude),
+            AbsoluteDirection::East => (x + magnitude, y),
+            AbsoluteDirection::South => (x, y - magnitude),
+            AbsoluteDirection::West => (x - magnitude, y),
+        };
+
+        let coordinate = Coordinate {
+            x: wrap_axis(x, self.x_min_boundary(), self.x_count()),
+            y: wrap_axis(y, self.y_min_boundary(), self.y_count()),
+        };
+        self.set_coordinate(&coordinate);
+        previous_coordinate != *self.position()
+    }
+
+    /// The coordinate within bounds that a coordinate corresponds to if the bounds wrap around at
+    /// the borders like a torus: the coordinate just past the east border is on the west border,
+    /// and so on. Coordinates within bounds are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::{Bounded, Bounds, Coordinate};
+    /// let bounds = Bounds::from_boundaries(-2, 2, -2, 2);
+    /// assert_eq!(bounds.wrap(&Coordinate { x: 3, y: -3 }), Coordinate { x: -2, y: 2 });
+    /// assert_eq!(bounds.wrap(&Coordinate { x: 12, y: 1 }), Coordinate { x: 2, y: 1 });
+    /// ```
+    fn wrap<C: Positioned>(&self, coordinate: &C) -> Coordinate {
+        Coordinate {
+            x: wrap_axis(
+                i64::from(coordinate.x_coordinate()),
+                self.x_min_boundary(),
+                self.x_count(),
+            ),
+            y: wrap_axis(
+                i64::from(coordinate.y_coordinate()),
+                self.y_min_boundary(),
+                self.y_count(),
+            ),
+        }
+    }
+}
+
+/// The value within `min..min + count` that is congruent to `value` modulo `count`.
+pub(crate) fn wrap_axis(value: i64, min: i32, count: AxisCount) -> i32 {
+    let offset = (value - i64::from(min)).rem_euclid(count.as_u64() as i64);
+    i32::try_from(i64::from(min) + offset).expect("the wrapped value is within bounds")
 }
 
 pub trait OriginCenteredness {
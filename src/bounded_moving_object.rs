@@ -328,6 +328,7 @@ impl DynamicallyBounded for BoundedMovingObject {
 mod tests {
 
     use super::*;
+    use crate::WrapMode;
     use crate::bounded::test::check_x_count;
     use crate::bounded::test::check_x_max;
     use crate::bounded::test::check_x_min;
@@ -380,6 +381,41 @@ mod tests {
         check_y_max(&pos, 4);
     }
 
+    #[test]
+    pub fn wrapping_move() {
+        let mut pos = BoundedMovingObject::new(-2, 2, -1, 1);
+        assert!(pos.move_in_absolute_direction_with(
+            AbsoluteDirection::East,
+            3,
+            WrapMode::Toroidal
+        ));
+        assert_eq!(pos.position(), &Coordinate { x: -2, y: 0 });
+        assert!(pos.move_in_absolute_direction_with(
+            AbsoluteDirection::South,
+            7,
+            WrapMode::Toroidal
+        ));
+        assert_eq!(pos.position(), &Coordinate { x: -2, y: -1 });
+
+        // a full lap ends where it started.
+        assert!(!pos.move_in_absolute_direction_with(
+            AbsoluteDirection::West,
+            5,
+            WrapMode::Toroidal
+        ));
+        assert!(!pos.move_in_absolute_direction_with(
+            AbsoluteDirection::East,
+            u32::MAX,
+            WrapMode::Toroidal
+        ));
+        assert!(!pos.move_in_absolute_direction_with(
+            AbsoluteDirection::West,
+            1,
+            WrapMode::Bounded
+        ));
+        assert_eq!(pos.position(), &Coordinate { x: -2, y: -1 });
+    }
+
     #[test]
     pub fn simple_move_with_bound_test_y_neg() {
         let mut pos = BoundedMovingObject::new(-10, 10, -10, 10);
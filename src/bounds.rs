@@ -80,6 +80,14 @@ impl Bounds {
         }
     }
 
+    /// Move the boundary facing the direction one step inwards. The caller has to make sure that
+    /// the bounds are more than one coordinate wide in the direction.
+    pub(crate) fn shrink_in_direction(&mut self, dir: AbsoluteDirection) {
+        for c in self.mut_coordinates_facing_direction(&dir) {
+            c.move_in_direction(&dir.inverse(), 1);
+        }
+    }
+
     pub fn add_top_row(&mut self) {
         self.northwest = self
             .northwest
@@ -121,6 +129,8 @@ impl<B: Bounded> PartialEq<B> for Bounds {
     }
 }
 
+impl Eq for Bounds {}
+
 impl OriginCenteredness for Bounds {
     type Distinguisher = MaybeOriginCentered;
 }
@@ -146,6 +156,8 @@ impl MaybeOriginBounded for Bounds {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::OutOfBoundsError;
+    use crate::ScreenCoordinate;
     use crate::bounded::test::check_x_count;
     use crate::bounded::test::check_x_len;
     use crate::bounded::test::check_x_max;
@@ -334,4 +346,65 @@ mod tests {
         check_to_grid_like!([(0,0), (10,-10)] with [5,5] is within bounds);
         check_to_grid_like!([(-5,5), (5, -5)] with [100,0] is out of bounds);
     }
+
+    #[test]
+    fn screen_coordinates() {
+        let bounds = Bounds::from_boundaries(-3, 1, 5, 7);
+        assert_eq!(
+            bounds.coordinate_to_screen(&bounds.northwest_corner()),
+            Ok(ScreenCoordinate { column: 0, row: 0 })
+        );
+        assert_eq!(
+            bounds.coordinate_to_screen(&bounds.southeast_corner()),
+            Ok(ScreenCoordinate { column: 4, row: 2 })
+        );
+        assert!(bounds.coordinate_to_screen(&Coordinate::default()).is_err());
+
+        for x in -3..=1 {
+            for y in 5..=7 {
+                let coordinate = Coordinate { x, y };
+                let screen = bounds.coordinate_to_screen(&coordinate).unwrap();
+                assert_eq!(bounds.screen_to_coordinate(screen), Ok(coordinate));
+            }
+        }
+
+        assert_eq!(
+            bounds.screen_to_coordinate(ScreenCoordinate { column: 5, row: 3 }),
+            Err(OutOfBoundsError::new(
+                Coordinate { x: 2, y: 4 },
+                AbsoluteDirection::South,
+                Some(AbsoluteDirection::East)
+            ))
+        );
+    }
+
+    #[test]
+    fn screen_coordinates_at_the_limits() {
+        let bounds = Bounds::from_boundaries(i32::MIN, i32::MAX, i32::MIN, i32::MAX);
+        let southeast = ScreenCoordinate {
+            column: u32::MAX,
+            row: u32::MAX,
+        };
+        assert_eq!(
+            bounds.screen_to_coordinate(southeast),
+            Ok(bounds.southeast_corner())
+        );
+        assert_eq!(
+            bounds.coordinate_to_screen(&bounds.southeast_corner()),
+            Ok(southeast)
+        );
+
+        let bounds = Bounds::from_boundaries(i32::MAX, i32::MAX, i32::MIN, i32::MIN);
+        assert_eq!(
+            bounds.screen_to_coordinate(ScreenCoordinate { column: 1, row: 0 }),
+            Err(OutOfBoundsError::new(
+                Coordinate {
+                    x: i32::MAX,
+                    y: i32::MIN
+                },
+                AbsoluteDirection::East,
+                None
+            ))
+        );
+    }
 }
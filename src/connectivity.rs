@@ -0,0 +1,192 @@
+use crate::Coordinate;
+use crate::Positioned;
+use crate::bounded::Bounded;
+use crate::bounded::wrap_axis;
+
+/// The offsets of the adjacent coordinates, in the order of [`Positioned::euclid_neighbors`]. The
+/// first four are the offsets of [`Positioned::manhattan_neighbors`].
+const NEIGHBOR_OFFSETS: [(i64, i64); 8] = [
+    (0, 1),
+    (1, 0),
+    (0, -1),
+    (-1, 0),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
+/// Which coordinates count as adjacent to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Connectivity {
+    /// The four coordinates that share an edge with a coordinate. See
+    /// [`Positioned::manhattan_neighbors`].
+    #[default]
+    Manhattan,
+
+    /// The eight coordinates that share an edge or a corner with a coordinate. See
+    /// [`Positioned::euclid_neighbors`].
+    Euclid,
+}
+
+impl Connectivity {
+    /// The adjacent coordinates of a position.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Connectivity;
+    /// use tudi::Coordinate;
+    /// assert_eq!(Connectivity::Manhattan.neighbors(&Coordinate::default()).len(), 4);
+    /// assert_eq!(Connectivity::Euclid.neighbors(&Coordinate::default()).len(), 8);
+    /// ```
+    pub fn neighbors<C: Positioned>(self, position: &C) -> Vec<Coordinate> {
+        match self {
+            Connectivity::Manhattan => position.manhattan_neighbors(),
+            Connectivity::Euclid => position.euclid_neighbors(),
+        }
+    }
+
+    /// The adjacent coordinates of a position that are within bounds. See also
+    /// [`Bounded::bounded_neighbors_to`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Connectivity;
+    /// use tudi::grid;
+    /// use tudi::bounded::Bounded;
+    /// let grid: tudi::Grid<()> = grid!(3, 3);
+    /// let corner = grid.northwest_corner();
+    /// assert_eq!(Connectivity::Manhattan.bounded_neighbors(&grid, corner).count(), 2);
+    /// assert_eq!(Connectivity::Euclid.bounded_neighbors(&grid, corner).count(), 3);
+    /// ```
+    pub fn bounded_neighbors<B: Bounded + ?Sized, C: Positioned>(
+        self,
+        bounds: &B,
+        position: C,
+    ) -> impl Iterator<Item = Coordinate> {
+        self.offset_neighbors(position).filter_map(|(x, y)| {
+            let neighbor = Coordinate {
+                x: i32::try_from(x).ok()?,
+                y: i32::try_from(y).ok()?,
+            };
+            bounds.is_within_bounds(&neighbor).then_some(neighbor)
+        })
+    }
+
+    /// The adjacent coordinates of a position, where the coordinates past a border of the bounds
+    /// wrap around to the opposite border. See also [`Bounded::wrap`].
+    pub(crate) fn wrapping_neighbors<B: Bounded + ?Sized, C: Positioned>(
+        self,
+        bounds: &B,
+        position: C,
+    ) -> impl Iterator<Item = Coordinate> {
+        self.offset_neighbors(position).map(|(x, y)| Coordinate {
+            x: wrap_axis(x, bounds.x_min_boundary(), bounds.x_count()),
+            y: wrap_axis(y, bounds.y_min_boundary(), bounds.y_count()),
+        })
+    }
+
+    /// The adjacent coordinates of a position in the order of [`Connectivity::neighbors`], widened
+    /// so that they cannot overflow.
+    fn offset_neighbors<C: Positioned>(self, position: C) -> impl Iterator<Item = (i64, i64)> {
+        let offsets = match self {
+            Connectivity::Manhattan => &NEIGHBOR_OFFSETS[..4],
+            Connectivity::Euclid => &NEIGHBOR_OFFSETS[..],
+        };
+        let (x, y) = (
+            i64::from(position.x_coordinate()),
+            i64::from(position.y_coordinate()),
+        );
+        offsets
+            .iter()
+            .map(move |(x_offset, y_offset)| (x + x_offset, y + y_offset))
+    }
+
+    /// The smallest number of steps between two positions when only moving between adjacent
+    /// coordinates. This is the Manhattan distance for [`Connectivity::Manhattan`] and the
+    /// Chebyshev distance for [`Connectivity::Euclid`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Connectivity;
+    /// use tudi::Coordinate;
+    /// let target = Coordinate { x: 3, y: -2 };
+    /// assert_eq!(Connectivity::Manhattan.distance(&Coordinate::default(), &target), 5);
+    /// assert_eq!(Connectivity::Euclid.distance(&Coordinate::default(), &target), 3);
+    /// ```
+    pub fn distance<C: Positioned, D: Positioned>(self, from: &C, to: &D) -> u64 {
+        let x_distance = u64::from(from.x_coordinate().abs_diff(to.x_coordinate()));
+        let y_distance = u64::from(from.y_coordinate().abs_diff(to.y_coordinate()));
+        match self {
+            Connectivity::Manhattan => x_distance + y_distance,
+            Connectivity::Euclid => x_distance.max(y_distance),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_are_one_step_away() {
+        let position = Coordinate { x: -4, y: 7 };
+        for connectivity in [Connectivity::Manhattan, Connectivity::Euclid] {
+            for neighbor in connectivity.neighbors(&position) {
+                assert_eq!(connectivity.distance(&position, &neighbor), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_neighbors_match_neighbors() {
+        let bounds = crate::Bounds::from_boundaries(-2, 2, -1, 1);
+        for connectivity in [Connectivity::Manhattan, Connectivity::Euclid] {
+            for position in [Coordinate::default(), Coordinate { x: 2, y: -1 }] {
+                let expected = connectivity
+                    .neighbors(&position)
+                    .into_iter()
+                    .filter(|neighbor| bounds.is_within_bounds(neighbor))
+                    .collect::<Vec<_>>();
+                assert!(
+                    connectivity
+                        .bounded_neighbors(&bounds, position)
+                        .eq(expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wrapping_neighbors_wrap_around_the_borders() {
+        let bounds = crate::Bounds::from_boundaries(-2, 2, -1, 1);
+        let corner = Coordinate { x: 2, y: -1 };
+        let neighbors = Connectivity::Euclid
+            .wrapping_neighbors(&bounds, corner)
+            .collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 8);
+        assert!(neighbors.contains(&Coordinate { x: -2, y: -1 }));
+        assert!(neighbors.contains(&Coordinate { x: 2, y: 1 }));
+        assert!(neighbors.contains(&Coordinate { x: -2, y: 1 }));
+    }
+
+    #[test]
+    fn distance_does_not_overflow() {
+        let from = Coordinate {
+            x: i32::MIN,
+            y: i32::MIN,
+        };
+        let to = Coordinate {
+            x: i32::MAX,
+            y: i32::MAX,
+        };
+        assert_eq!(
+            Connectivity::Manhattan.distance(&from, &to),
+            2 * u64::from(u32::MAX)
+        );
+        assert_eq!(
+            Connectivity::Euclid.distance(&from, &to),
+            u64::from(u32::MAX)
+        );
+    }
+}
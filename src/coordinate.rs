@@ -46,6 +46,16 @@ impl Coordinate {
     pub fn is_below_row(&self, row: i32) -> bool {
         self.y_coordinate() <= row
     }
+
+    /// Checks if the coordinate is left of a column. If the coordinate is on the column the function returns true.
+    pub fn is_left_of_column(&self, column: i32) -> bool {
+        self.x_coordinate() <= column
+    }
+
+    /// Checks if the coordinate is right of a column. If the coordinate is on the column the function returns true.
+    pub fn is_right_of_column(&self, column: i32) -> bool {
+        self.x_coordinate() >= column
+    }
 }
 
 impl Add for Coordinate {
@@ -244,4 +254,20 @@ mod tests {
         assert!(c.is_below_row(0));
         assert!(c.is_below_row(1));
     }
+
+    #[test]
+    pub fn should_be_left_of_column() {
+        let c = Coordinate::default();
+        assert!(!c.is_left_of_column(-2));
+        assert!(c.is_left_of_column(0));
+        assert!(c.is_left_of_column(1));
+    }
+
+    #[test]
+    pub fn should_be_right_of_column() {
+        let c = Coordinate::default();
+        assert!(c.is_right_of_column(-2));
+        assert!(c.is_right_of_column(0));
+        assert!(!c.is_right_of_column(1));
+    }
 }
@@ -0,0 +1,84 @@
+use crate::Positioned;
+
+/// How the distance between two coordinates is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistanceMetric {
+    /// The sum of the distances along each axis. Coordinates within a radius form a diamond.
+    Manhattan,
+
+    /// The largest of the distances along each axis. Coordinates within a radius form a square.
+    Chebyshev,
+
+    /// The straight-line distance. Coordinates within a radius form a circle.
+    Euclidean,
+}
+
+impl DistanceMetric {
+    /// Whether the distance between two positions is at most `radius`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::DistanceMetric;
+    /// let corner = Coordinate { x: 2, y: 2 };
+    /// assert!(!DistanceMetric::Manhattan.is_within(&Coordinate::default(), &corner, 3));
+    /// assert!(DistanceMetric::Chebyshev.is_within(&Coordinate::default(), &corner, 3));
+    /// assert!(DistanceMetric::Euclidean.is_within(&Coordinate::default(), &corner, 3));
+    /// ```
+    pub fn is_within<C: Positioned, D: Positioned>(self, from: &C, to: &D, radius: u32) -> bool {
+        let x_distance = u64::from(from.x_coordinate().abs_diff(to.x_coordinate()));
+        let y_distance = u64::from(from.y_coordinate().abs_diff(to.y_coordinate()));
+        let radius = u64::from(radius);
+        match self {
+            DistanceMetric::Manhattan => x_distance + y_distance <= radius,
+            DistanceMetric::Chebyshev => x_distance.max(y_distance) <= radius,
+            DistanceMetric::Euclidean => {
+                // The squares can exceed u64::MAX for coordinates at opposite ends of the i32 range.
+                u128::from(x_distance).pow(2) + u128::from(y_distance).pow(2)
+                    <= u128::from(radius).pow(2)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coordinate;
+
+    fn count_within(metric: DistanceMetric, radius: u32) -> usize {
+        let range = -(radius as i32)..=radius as i32;
+        range
+            .clone()
+            .flat_map(|x| range.clone().map(move |y| Coordinate { x, y }))
+            .filter(|coordinate| metric.is_within(&Coordinate::default(), coordinate, radius))
+            .count()
+    }
+
+    #[test]
+    fn shapes() {
+        assert_eq!(count_within(DistanceMetric::Manhattan, 3), 25);
+        assert_eq!(count_within(DistanceMetric::Chebyshev, 3), 49);
+        assert_eq!(count_within(DistanceMetric::Euclidean, 3), 29);
+    }
+
+    #[test]
+    fn extreme_coordinates() {
+        let from = Coordinate {
+            x: i32::MIN,
+            y: i32::MIN,
+        };
+        let to = Coordinate {
+            x: i32::MAX,
+            y: i32::MAX,
+        };
+        for metric in [
+            DistanceMetric::Manhattan,
+            DistanceMetric::Chebyshev,
+            DistanceMetric::Euclidean,
+        ] {
+            assert!(!metric.is_within(&from, &to, u32::MAX - 1));
+        }
+        assert!(DistanceMetric::Chebyshev.is_within(&from, &to, u32::MAX));
+    }
+}
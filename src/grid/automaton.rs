@@ -0,0 +1,434 @@
+use super::Grid;
+use super::grid_storage::RowMajorKey;
+use crate::Connectivity;
+use crate::Coordinate;
+use crate::bounded::Bounded;
+use crate::bounded::OriginBounded;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A rule that decides the element at a coordinate in the next generation of a [`Grid::step`].
+///
+/// Closures of the form `FnMut(Option<&T>, usize) -> Option<T>` are rules, and so is a
+/// [`LifeRule`].
+pub trait Rule<T> {
+    /// The next element at a coordinate, given its current element and the number of occupied
+    /// neighbors.
+    fn apply(&mut self, element: Option<&T>, occupied_neighbors: usize) -> Option<T>;
+}
+
+impl<T, F: FnMut(Option<&T>, usize) -> Option<T>> Rule<T> for F {
+    fn apply(&mut self, element: Option<&T>, occupied_neighbors: usize) -> Option<T> {
+        self(element, occupied_neighbors)
+    }
+}
+
+/// A life-like rule in the "B3/S23" notation: an empty coordinate is born with one of the counts
+/// of occupied neighbors after the B, and an occupied coordinate survives with one of the counts
+/// after the S.
+///
+/// Surviving elements are cloned into the next generation and born elements are
+/// [`Default::default`]. Births without occupied neighbors ("B0") are not supported, since
+/// [`Grid::step`] leaves empty coordinates without occupied neighbors empty.
+///
+/// # Examples
+/// ```
+/// use tudi::LifeRule;
+/// let highlife: LifeRule = "B36/S23".parse().unwrap();
+/// assert!(highlife.is_born(6));
+/// assert!(!highlife.survives(6));
+/// assert_eq!(highlife.to_string(), "B36/S23");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LifeRule {
+    /// One bit for each neighbor count from 0 to 8.
+    birth: u16,
+    survival: u16,
+}
+
+impl LifeRule {
+    /// Conway's Game of Life, "B3/S23".
+    pub const CONWAY: Self = Self {
+        birth: 1 << 3,
+        survival: (1 << 2) | (1 << 3),
+    };
+
+    /// Whether an element is born at an empty coordinate with this many occupied neighbors.
+    pub fn is_born(&self, occupied_neighbors: usize) -> bool {
+        occupied_neighbors <= 8 && self.birth & (1 << occupied_neighbors) != 0
+    }
+
+    /// Whether an element survives with this many occupied neighbors.
+    pub fn survives(&self, occupied_neighbors: usize) -> bool {
+        occupied_neighbors <= 8 && self.survival & (1 << occupied_neighbors) != 0
+    }
+}
+
+impl<T: Clone + Default> Rule<T> for LifeRule {
+    fn apply(&mut self, element: Option<&T>, occupied_neighbors: usize) -> Option<T> {
+        match element {
+            Some(element) => self.survives(occupied_neighbors).then(|| element.clone()),
+            None => self.is_born(occupied_neighbors).then(T::default),
+        }
+    }
+}
+
+/// An error that occurs when parsing a [`LifeRule`].
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum LifeRuleError {
+    #[error("Expected a rule of the form B3/S23, found {0:?}")]
+    Format(String),
+    #[error("Expected a neighbor count from 0 to 8, found {0:?}")]
+    NeighborCount(char),
+    #[error("Births without occupied neighbors (B0) are not supported")]
+    BirthWithoutNeighbors,
+}
+
+impl FromStr for LifeRule {
+    type Err = LifeRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format_error = || LifeRuleError::Format(s.to_string());
+        let (birth, survival) = s.trim().split_once('/').ok_or_else(format_error)?;
+        let birth = birth.strip_prefix(['B', 'b']).ok_or_else(format_error)?;
+        let survival = survival.strip_prefix(['S', 's']).ok_or_else(format_error)?;
+
+        let rule = Self {
+            birth: neighbor_counts(birth)?,
+            survival: neighbor_counts(survival)?,
+        };
+        if rule.is_born(0) {
+            return Err(LifeRuleError::BirthWithoutNeighbors);
+        }
+        Ok(rule)
+    }
+}
+
+/// The bits of the neighbor counts in a string of digits.
+fn neighbor_counts(digits: &str) -> Result<u16, LifeRuleError> {
+    digits.chars().try_fold(0, |counts, digit| {
+        match digit.to_digit(10).filter(|count| *count <= 8) {
+            Some(count) => Ok(counts | (1 << count)),
+            None => Err(LifeRuleError::NeighborCount(digit)),
+        }
+    })
+}
+
+impl fmt::Display for LifeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "B")?;
+        for count in (0..=8).filter(|count| self.is_born(*count)) {
+            write!(f, "{count}")?;
+        }
+        write!(f, "/S")?;
+        for count in (0..=8).filter(|count| self.survives(*count)) {
+            write!(f, "{count}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Advance the grid by one generation of a cellular automaton.
+    ///
+    /// The rule is applied to every occupied coordinate and every empty coordinate with at least
+    /// one occupied neighbor, and decides what is stored there in the next generation. Empty
+    /// coordinates without occupied neighbors stay empty. All coordinates are updated at once, so
+    /// the rule only sees the current generation. The rule is called in the order of
+    /// [`iter_new`](Grid::iter_new()).
+    ///
+    /// A densely stored grid is updated in place, and buffers the occupancy of the current
+    /// generation. A sparsely stored grid builds the next generation from the occupied coordinates
+    /// and their neighbors.
+    ///
+    /// The occupied neighbors are counted with [`neighbors`](Grid::neighbors()), so they follow the
+    /// [`WrapMode`](crate::WrapMode) of the grid: coordinates out of bounds count as empty on a
+    /// bounded grid, and the neighbors past one edge of a toroidal grid are on the opposite edge.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tudi::Connectivity;
+    /// use tudi::Grid;
+    /// use tudi::LifeRule;
+    /// let cells = HashMap::from([('#', ())]);
+    /// let mut blinker = Grid::from_str_by_map("...\n###\n...", &cells).unwrap();
+    ///
+    /// blinker.step(Connectivity::Euclid, LifeRule::CONWAY);
+    /// assert_eq!(blinker.element_statuses(), ".#.\n.#.\n.#.");
+    ///
+    /// // Closures are rules too. Here every element counts how many generations it has lived.
+    /// let mut ages = blinker.map(|_| 0);
+    /// let mut age = |age: Option<&u32>, neighbors| match age {
+    ///     Some(age) => (neighbors == 1).then_some(age + 1),
+    ///     None => None,
+    /// };
+    /// ages.step(Connectivity::Euclid, &mut age);
+    /// assert_eq!(ages.iter_elements_new().map(|(_, age)| *age).collect::<Vec<_>>(), [1, 1]);
+    /// ```
+    pub fn step(&mut self, connectivity: Connectivity, rule: impl Rule<T>) {
+        if self.storage.is_dense() {
+            self.step_dense(connectivity, rule);
+        } else {
+            self.step_sparse(connectivity, rule);
+        }
+        self.apply_performance_tuning();
+    }
+
+    /// [`step`](Grid::step()) for dense storage. The occupancy of the current generation is
+    /// buffered, so that the neighbors are counted in the current generation while the storage is
+    /// updated to the next one in place.
+    fn step_dense(&mut self, connectivity: Connectivity, mut rule: impl Rule<T>) {
+        let width = OriginBounded::x_count(self).as_u64() as usize;
+        let (x_min, y_max) = (self.x_min_boundary(), self.y_max_boundary());
+        let occupied = self
+            .iter_new()
+            .map(|(_, element)| element.is_some())
+            .collect::<Vec<_>>();
+        let was_occupied = |coordinate: &Coordinate| {
+            occupied[y_max.abs_diff(coordinate.y) as usize * width
+                + coordinate.x.abs_diff(x_min) as usize]
+        };
+
+        for y in (self.y_min_boundary()..=y_max).rev() {
+            for x in x_min..=self.x_max_boundary() {
+                let coordinate = Coordinate { x, y };
+                let occupied_neighbors = self
+                    .neighbors_iter(&coordinate, connectivity)
+                    .filter(was_occupied)
+                    .count();
+                if !was_occupied(&coordinate) && occupied_neighbors == 0 {
+                    continue;
+                }
+
+                match rule.apply(self.element_unchecked(&coordinate), occupied_neighbors) {
+                    Some(element) => {
+                        self.store_element_without_tuning(&coordinate, element)
+                            .expect("the coordinate is within bounds");
+                    }
+                    None if was_occupied(&coordinate) => {
+                        self.remove_element_without_tuning(&coordinate)
+                            .expect("the coordinate is occupied");
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+
+    /// [`step`](Grid::step()) for sparse storage. Only the occupied coordinates and their
+    /// neighbors are visited, and the next generation is built in a separate grid.
+    fn step_sparse(&mut self, connectivity: Connectivity, mut rule: impl Rule<T>) {
+        let candidates = self
+            .iter_elements_new()
+            .flat_map(|(coordinate, _)| {
+                std::iter::once(coordinate).chain(self.neighbors_iter(&coordinate, connectivity))
+            })
+            .map(RowMajorKey::new)
+            .collect::<BTreeSet<_>>();
+
+        let mut next = Grid::with_count(OriginBounded::x_count(self), OriginBounded::y_count(self))
+            .with_performance_tuning(self.performance_tuning)
+            .with_wrap_mode(self.wrap_mode);
+        for coordinate in candidates.into_iter().map(RowMajorKey::coordinate) {
+            let occupied_neighbors = self
+                .neighbors_iter(&coordinate, connectivity)
+                .filter(|neighbor| self.element_unchecked(neighbor).is_some())
+                .count();
+            if let Some(element) =
+                rule.apply(self.element_unchecked(&coordinate), occupied_neighbors)
+            {
+                next.store_element(&coordinate, element)
+                    .expect("the candidates are within bounds");
+            }
+        }
+
+        *self = next;
+    }
+
+    /// Advance the grid by one generation like [`step`](Grid::step()), after growing it with
+    /// [`add_row`](Grid::add_row()) and [`add_column`](Grid::add_column()) where elements are on
+    /// its border, so that elements are never born out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tudi::Connectivity;
+    /// use tudi::Grid;
+    /// use tudi::LifeRule;
+    /// let cells = HashMap::from([('#', ())]);
+    /// let mut blinker = Grid::from_str_by_map("###", &cells).unwrap();
+    ///
+    /// blinker.step_expanding(Connectivity::Euclid, LifeRule::CONWAY);
+    /// assert_eq!(blinker.element_statuses(), "..#..\n..#..\n..#..");
+    /// ```
+    pub fn step_expanding(&mut self, connectivity: Connectivity, rule: impl Rule<T>) {
+        self.expand_around_elements();
+        self.step(connectivity, rule);
+    }
+
+    /// Add a row or column on both sides of each axis where an element is on the border.
+    fn expand_around_elements(&mut self) {
+        let on_horizontal_border = self.iter_elements_new().any(|(coordinate, _)| {
+            coordinate.y == self.y_max_boundary() || coordinate.y == self.y_min_boundary()
+        });
+        let on_vertical_border = self.iter_elements_new().any(|(coordinate, _)| {
+            coordinate.x == self.x_max_boundary() || coordinate.x == self.x_min_boundary()
+        });
+
+        if on_horizontal_border {
+            self.add_row();
+            self.add_row();
+        }
+        if on_vertical_border {
+            self.add_column();
+            self.add_column();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PerformanceTuning;
+    use crate::WrapMode;
+    use std::collections::HashMap;
+
+    const GLIDER: &str = "\
+.#...
+..#..
+###..
+.....
+.....";
+
+    fn grid(cells: &str) -> Grid<()> {
+        Grid::from_str_by_map(cells, &HashMap::from([('#', ())])).unwrap()
+    }
+
+    #[test]
+    fn parse_life_rules() {
+        assert_eq!("B3/S23".parse(), Ok(LifeRule::CONWAY));
+        assert_eq!("b3/s32".parse(), Ok(LifeRule::CONWAY));
+        assert_eq!(LifeRule::CONWAY.to_string(), "B3/S23");
+
+        let seeds: LifeRule = "B2/S".parse().unwrap();
+        assert!(seeds.is_born(2));
+        assert!((0..=8).all(|count| !seeds.survives(count)));
+        assert_eq!(seeds.to_string(), "B2/S");
+    }
+
+    #[test]
+    fn parse_invalid_life_rules() {
+        assert_eq!(
+            "S23/B3".parse::<LifeRule>(),
+            Err(LifeRuleError::Format("S23/B3".to_string()))
+        );
+        assert_eq!(
+            "B3S23".parse::<LifeRule>(),
+            Err(LifeRuleError::Format("B3S23".to_string()))
+        );
+        assert_eq!(
+            "B39/S23".parse::<LifeRule>(),
+            Err(LifeRuleError::NeighborCount('9'))
+        );
+        assert_eq!(
+            "B03/S23".parse::<LifeRule>(),
+            Err(LifeRuleError::BirthWithoutNeighbors)
+        );
+        assert!("B3/S023".parse::<LifeRule>().is_ok());
+    }
+
+    #[test]
+    fn still_life_in_a_corner() {
+        let mut block = grid("##.\n##.\n...");
+        block.step(Connectivity::Euclid, LifeRule::CONWAY);
+        assert_eq!(block.element_statuses(), "##.\n##.\n...");
+    }
+
+    #[test]
+    fn bounded_edges_cut_off_births() {
+        let mut glider = grid(GLIDER);
+        for _ in 0..12 {
+            glider.step(Connectivity::Euclid, LifeRule::CONWAY);
+        }
+        // The glider turns into a block in the southeast corner.
+        assert_eq!(
+            glider.element_statuses(),
+            ".....\n.....\n.....\n...##\n...##"
+        );
+    }
+
+    #[test]
+    fn toroidal_glider_returns_to_its_start() {
+        let mut glider = grid(GLIDER).with_wrap_mode(WrapMode::Toroidal);
+        for generation in 1..=20 {
+            glider.step(Connectivity::Euclid, LifeRule::CONWAY);
+            assert_eq!(glider.iter_elements_new().count(), 5);
+            assert_eq!(glider.element_statuses() == GLIDER, generation == 20);
+        }
+    }
+
+    #[test]
+    fn expanding_glider_keeps_flying() {
+        let mut glider = grid(GLIDER);
+        for _ in 0..20 {
+            glider.step_expanding(Connectivity::Euclid, LifeRule::CONWAY);
+            assert_eq!(glider.iter_elements_new().count(), 5);
+        }
+        let (glider, offset) = glider.crop_to_elements();
+        assert_eq!(glider.element_statuses(), ".#.\n..#\n###");
+        // The glider has moved five coordinates to the southeast.
+        assert_eq!(offset, Coordinate { x: -4, y: 4 });
+    }
+
+    #[test]
+    fn dense_and_sparse_steps_agree() {
+        let patterns = [GLIDER, "#.##\n.##.", "##\n#.", "#.#"];
+        for (pattern, wrap_mode, connectivity) in itertools::iproduct!(
+            patterns,
+            [WrapMode::Bounded, WrapMode::Toroidal],
+            [Connectivity::Euclid, Connectivity::Manhattan]
+        ) {
+            let [memory, speed] =
+                [PerformanceTuning::Memory, PerformanceTuning::Speed].map(|tuning| {
+                    let mut grid = grid(pattern)
+                        .map(|_| 0)
+                        .with_performance_tuning(tuning)
+                        .with_wrap_mode(wrap_mode);
+                    let mut calls = Vec::new();
+                    for _ in 0..6 {
+                        grid.step(connectivity, |age: Option<&u32>, neighbors| {
+                            calls.push((age.copied(), neighbors));
+                            match age {
+                                Some(age) => (neighbors % 2 == 1).then_some(age + 1),
+                                None => (neighbors == 2).then_some(0),
+                            }
+                        });
+                    }
+                    (grid, calls)
+                });
+            assert!(!memory.0.storage.is_dense());
+            assert!(speed.0.storage.is_dense());
+            assert_eq!(memory.0, speed.0);
+            assert_eq!(memory.1, speed.1);
+        }
+    }
+
+    #[test]
+    fn closure_rule_with_manhattan_neighbors() {
+        let mut grid = grid(".....\n.....\n..#..\n.....\n.....").map(|_| 0);
+        let mut grow = |distance: Option<&u32>, neighbors: usize| match distance {
+            Some(distance) => Some(*distance),
+            None => (neighbors > 0).then_some(neighbors as u32),
+        };
+
+        grid.step(Connectivity::Manhattan, &mut grow);
+        assert_eq!(grid.element_statuses(), ".....\n..#..\n.###.\n..#..\n.....");
+        grid.step(Connectivity::Manhattan, &mut grow);
+        assert_eq!(grid.element_statuses(), "..#..\n.###.\n#####\n.###.\n..#..");
+        assert_eq!(grid.element(&Coordinate { x: 1, y: 1 }), Ok(&2));
+        assert_eq!(grid.element(&Coordinate { x: 0, y: 2 }), Ok(&1));
+    }
+}
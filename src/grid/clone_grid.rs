@@ -1,4 +1,5 @@
 use super::{Grid, GridCreationError};
+use crate::bounded::OriginBounded;
 use std::collections::HashMap;
 
 impl<T: Clone> Grid<T> {
@@ -11,6 +12,10 @@ impl<T: Clone> Grid<T> {
     ///
     /// This method inherits its definition of a line break from the [lines](str::lines) method.
     ///
+    /// The first line is the northernmost row. The character at a column of a line is at
+    /// [`ScreenCoordinate`](crate::ScreenCoordinate) `{ column, row: line }`, see
+    /// [`Bounded::screen_to_coordinate`](crate::Bounded::screen_to_coordinate()).
+    ///
     /// # Errors
     ///
     /// This method returns an error if input rows have different lengths.
@@ -40,19 +45,84 @@ impl<T: Clone> Grid<T> {
 
         Grid::<T>::try_from(data)
     }
-}
 
-impl<T: Clone> PartialEq for Grid<T> {
-    /// This does not check T for equality (since T does not necessarily implement partialEq), but it checks
-    /// that types, sizes and so forth are identical.
-    fn eq(&self, other: &Self) -> bool {
-        // check meta.
-        if self.x_count() != other.x_count() || self.y_count() != other.y_count() {
-            return false;
-        };
-        self.iter_new()
-            .zip(other.iter_new())
-            .all(|((_, a), (_, b))| a.is_some() == b.is_some())
+    /// The eight variants of the grid under rotation and mirroring.
+    ///
+    /// The variants are yielded in the following order: the grid itself, rotated a quarter turn
+    /// clockwise, rotated half a turn, rotated three quarter turns clockwise, and then the same
+    /// four rotations of the grid mirrored from west to east. A grid with symmetries of its own
+    /// yields some variants more than once.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let mut grid = grid!(2, 2);
+    /// grid.store_element(&Coordinate { x: 0, y: 1 }, ());
+    ///
+    /// // each corner is occupied by two of the variants.
+    /// let occupied_corners = grid
+    ///     .symmetries()
+    ///     .filter(|variant| variant.element(&Coordinate { x: 1, y: 0 }).is_ok())
+    ///     .count();
+    /// assert_eq!(occupied_corners, 2);
+    /// ```
+    pub fn symmetries(&self) -> impl Iterator<Item = Grid<T>> + '_ {
+        (0..8).map(|variant| {
+            let mut grid = self.clone();
+            if variant >= 4 {
+                grid.flip_horizontal();
+            }
+            for _ in 0..variant % 4 {
+                grid.rotate_clockwise();
+            }
+            grid
+        })
+    }
+
+    /// A representative of all grids that are equal to this grid up to rotation and mirroring.
+    ///
+    /// The canonical form is the smallest of the [symmetries](Self::symmetries), ordered first by
+    /// x-count and y-count and then by the elements from west to east, north to south, where an
+    /// empty coordinate is smaller than any element. Two grids that are rotations or mirror images
+    /// of each other therefore have the same canonical form.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let mut first = grid!(3, 2);
+    /// first.store_element(&Coordinate { x: -1, y: 1 }, 1);
+    /// let mut second = grid!(2, 3);
+    /// second.store_element(&Coordinate { x: 0, y: -1 }, 1);
+    ///
+    /// assert_eq!(
+    ///     first.canonical_form().element_statuses(),
+    ///     second.canonical_form().element_statuses()
+    /// );
+    /// ```
+    pub fn canonical_form(&self) -> Grid<T>
+    where
+        T: Ord,
+    {
+        self.symmetries()
+            .min_by(|first, second| {
+                (
+                    OriginBounded::x_count(first).as_u64(),
+                    OriginBounded::y_count(first).as_u64(),
+                )
+                    .cmp(&(
+                        OriginBounded::x_count(second).as_u64(),
+                        OriginBounded::y_count(second).as_u64(),
+                    ))
+                    .then_with(|| {
+                        first
+                            .iter_new()
+                            .map(|(_, element)| element)
+                            .cmp(second.iter_new().map(|(_, element)| element))
+                    })
+            })
+            .expect("a grid always has eight symmetries")
     }
 }
 
@@ -85,6 +155,63 @@ pub mod tests {
         }
     }
 
+    pub mod symmetry_tests {
+        use super::*;
+
+        fn grid_from(input: &str) -> Grid<char> {
+            let map: HashMap<char, char> = ('a'..='z').map(|c| (c, c)).collect();
+            Grid::from_str_by_map(input, &map).unwrap()
+        }
+
+        fn values(grid: &Grid<char>) -> Vec<Option<char>> {
+            grid.iter_new().map(|(_, e)| e.copied()).collect()
+        }
+
+        #[test]
+        fn asymmetric_grid_has_eight_distinct_symmetries() {
+            let grid = grid_from("ab.\n..c");
+            let variants = grid
+                .symmetries()
+                .map(|variant| {
+                    (
+                        variant.x_count().as_u64(),
+                        variant.y_count().as_u64(),
+                        values(&variant),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(variants.len(), 8);
+            assert_eq!(variants.iter().unique().count(), 8);
+            assert_eq!(variants[0], (3, 2, values(&grid)));
+        }
+
+        #[test]
+        fn symmetric_grid_repeats_symmetries() {
+            let grid = grid_from("a.a\n.b.\na.a");
+            assert!(
+                grid.symmetries()
+                    .all(|variant| values(&variant) == values(&grid))
+            );
+        }
+
+        #[test]
+        fn canonical_form_is_shared_by_all_symmetries() {
+            for input in ["ab.\n..c", "a...\n..b.\nc..d", "ab\ncd"] {
+                let canonical = values(&grid_from(input).canonical_form());
+                for variant in grid_from(input).symmetries() {
+                    assert_eq!(values(&variant.canonical_form()), canonical);
+                }
+            }
+        }
+
+        #[test]
+        fn canonical_form_distinguishes_values() {
+            let first = grid_from("ab\n..").canonical_form();
+            let second = grid_from("aa\n..").canonical_form();
+            assert_ne!(values(&first), values(&second));
+        }
+    }
+
     pub mod constructor_tests {
         use super::*;
         use crate::bounded::test::check_x_count;
@@ -0,0 +1,307 @@
+use super::Grid;
+use crate::Coordinate;
+use crate::DistanceMetric;
+use crate::OutOfBoundsError;
+use crate::bounded::Bounded;
+use std::collections::HashSet;
+
+impl<T> Grid<T> {
+    /// The coordinates that are visible from the origin, computed with symmetric shadowcasting.
+    ///
+    /// A coordinate is visible if it is within `radius` of the origin according to the metric and
+    /// the line of sight from the origin is not blocked by an opaque coordinate. Opaque
+    /// coordinates are visible themselves, but hide the coordinates behind them. Coordinates out of
+    /// bounds are never visible and block the view.
+    ///
+    /// The result is symmetric: if a transparent coordinate `b` is visible from `a`, then `a` is
+    /// visible from `b` with the same radius. The origin is always visible.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the origin is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tudi::Coordinate;
+    /// use tudi::DistanceMetric;
+    /// use tudi::Grid;
+    /// let walls = HashMap::from([('#', ())]);
+    /// let room = Grid::from_str_by_map(".....\n.....\n..#..\n.....\n.....", &walls).unwrap();
+    ///
+    /// let visible = room
+    ///     .field_of_view(Coordinate { x: 0, y: -2 }, 10, DistanceMetric::Euclidean, |_, wall| {
+    ///         wall.is_some()
+    ///     })
+    ///     .unwrap();
+    /// assert!(visible.contains(&Coordinate { x: 0, y: 0 })); // the wall
+    /// assert!(!visible.contains(&Coordinate { x: 0, y: 2 })); // behind the wall
+    /// assert!(visible.contains(&Coordinate { x: 2, y: 2 }));
+    /// ```
+    pub fn field_of_view(
+        &self,
+        origin: Coordinate,
+        radius: u32,
+        metric: DistanceMetric,
+        mut is_opaque: impl FnMut(Coordinate, Option<&T>) -> bool,
+    ) -> Result<HashSet<Coordinate>, OutOfBoundsError> {
+        self.coordinate_to_index(&origin)?;
+        let mut visible = HashSet::from([origin]);
+
+        for quadrant in [
+            Quadrant::North,
+            Quadrant::East,
+            Quadrant::South,
+            Quadrant::West,
+        ] {
+            let mut rows = vec![Row {
+                depth: 1,
+                start: Slope::new(-1, 1),
+                end: Slope::new(1, 1),
+            }];
+
+            while let Some(mut row) = rows.pop() {
+                if row.depth > i64::from(radius) {
+                    continue;
+                }
+
+                let mut previous_is_opaque = None;
+                for column in row.columns() {
+                    let coordinate = quadrant.coordinate(origin, row.depth, column);
+                    let opaque = coordinate.is_none_or(|coordinate| {
+                        !self.is_within_bounds(&coordinate)
+                            || is_opaque(coordinate, self.element_unchecked(&coordinate))
+                    });
+
+                    if let Some(coordinate) = coordinate
+                        && self.is_within_bounds(&coordinate)
+                        && (opaque || row.is_symmetric(column))
+                        && metric.is_within(&origin, &coordinate, radius)
+                    {
+                        visible.insert(coordinate);
+                    }
+
+                    if previous_is_opaque == Some(true) && !opaque {
+                        row.start = Slope::of_edge(row.depth, column);
+                    }
+                    if previous_is_opaque == Some(false) && opaque {
+                        rows.push(Row {
+                            depth: row.depth + 1,
+                            start: row.start,
+                            end: Slope::of_edge(row.depth, column),
+                        });
+                    }
+                    previous_is_opaque = Some(opaque);
+                }
+
+                if previous_is_opaque == Some(false) {
+                    rows.push(Row {
+                        depth: row.depth + 1,
+                        ..row
+                    });
+                }
+            }
+        }
+
+        Ok(visible)
+    }
+}
+
+/// One of the four triangular sections around the origin that are scanned separately. Within a
+/// quadrant, the depth is the distance from the origin along the quadrant's direction and the
+/// column is the offset across it.
+#[derive(Clone, Copy)]
+enum Quadrant {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Quadrant {
+    /// The coordinate at a depth and column, or `None` if it does not fit in a coordinate.
+    fn coordinate(self, origin: Coordinate, depth: i64, column: i64) -> Option<Coordinate> {
+        let (x, y) = (i64::from(origin.x), i64::from(origin.y));
+        let (x, y) = match self {
+            Quadrant::North => (x + column, y + depth),
+            Quadrant::East => (x + depth, y + column),
+            Quadrant::South => (x + column, y - depth),
+            Quadrant::West => (x - depth, y + column),
+        };
+        Some(Coordinate {
+            x: i32::try_from(x).ok()?,
+            y: i32::try_from(y).ok()?,
+        })
+    }
+}
+
+/// An exact fraction with a positive denominator.
+#[derive(Clone, Copy)]
+struct Slope {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Slope {
+    fn new(numerator: i64, denominator: i64) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// The slope of the edge between a column and the column before it.
+    fn of_edge(depth: i64, column: i64) -> Self {
+        Self::new(2 * column - 1, 2 * depth)
+    }
+}
+
+/// The columns at one depth of a quadrant that lie between two slopes.
+#[derive(Clone, Copy)]
+struct Row {
+    depth: i64,
+    start: Slope,
+    end: Slope,
+}
+
+impl Row {
+    /// The columns whose centers are within the slopes, with ties rounded toward the inside.
+    fn columns(&self) -> std::ops::RangeInclusive<i64> {
+        // round(depth * start), rounding half up.
+        let first = (2 * self.depth * self.start.numerator + self.start.denominator)
+            .div_euclid(2 * self.start.denominator);
+        // round(depth * end), rounding half down.
+        let last = -(-(2 * self.depth * self.end.numerator - self.end.denominator))
+            .div_euclid(2 * self.end.denominator);
+        first..=last
+    }
+
+    /// Whether the center of a column is within the slopes of the row. Only such columns are
+    /// visible, which makes the field of view symmetric.
+    fn is_symmetric(&self, column: i64) -> bool {
+        column * self.start.denominator >= self.depth * self.start.numerator
+            && column * self.end.denominator <= self.depth * self.end.numerator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+    use std::collections::HashMap;
+
+    const MAP: &str = "\
+...........
+..#....#...
+...........
+....#......
+.......##..
+...........
+.#.........
+.....#.....
+...........";
+
+    /// An 11x9 grid where '#' is opaque.
+    fn map() -> Grid<()> {
+        Grid::from_str_by_map(MAP, &HashMap::from([('#', ())])).unwrap()
+    }
+
+    fn is_wall(_: Coordinate, element: Option<&()>) -> bool {
+        element.is_some()
+    }
+
+    fn open_grid() -> Grid<()> {
+        Grid::with_count(
+            AxisCount::from_u64_unchecked(9),
+            AxisCount::from_u64_unchecked(9),
+        )
+    }
+
+    #[test]
+    fn metrics_shape_an_open_field() {
+        let grid = open_grid();
+        for (metric, count) in [
+            (DistanceMetric::Manhattan, 25),
+            (DistanceMetric::Chebyshev, 49),
+            (DistanceMetric::Euclidean, 29),
+        ] {
+            let visible = grid
+                .field_of_view(Coordinate::default(), 3, metric, is_wall)
+                .unwrap();
+            assert_eq!(visible.len(), count);
+            assert!(visible.iter().all(|coordinate| metric.is_within(
+                &Coordinate::default(),
+                coordinate,
+                3
+            )));
+        }
+    }
+
+    #[test]
+    fn zero_radius() {
+        let visible = open_grid()
+            .field_of_view(Coordinate::default(), 0, DistanceMetric::Chebyshev, is_wall)
+            .unwrap();
+        assert_eq!(visible, HashSet::from([Coordinate::default()]));
+    }
+
+    #[test]
+    fn bounds_limit_the_view() {
+        let grid = open_grid();
+        let corner = grid.northeast_corner();
+        let visible = grid
+            .field_of_view(corner, 100, DistanceMetric::Chebyshev, is_wall)
+            .unwrap();
+        assert_eq!(visible.len(), 81);
+        assert!(
+            grid.field_of_view(
+                Coordinate { x: 5, y: 0 },
+                1,
+                DistanceMetric::Chebyshev,
+                is_wall
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn walls_cast_shadows() {
+        let mut grid = open_grid();
+        grid.store_element(&Coordinate { x: 1, y: 0 }, ()).unwrap();
+        let visible = grid
+            .field_of_view(Coordinate::default(), 4, DistanceMetric::Chebyshev, is_wall)
+            .unwrap();
+
+        assert!(visible.contains(&Coordinate { x: 1, y: 0 }));
+        for x in 2..=4 {
+            assert!(!visible.contains(&Coordinate { x, y: 0 }));
+        }
+        assert!(visible.contains(&Coordinate { x: 4, y: 2 }));
+        assert!(visible.contains(&Coordinate { x: -4, y: 0 }));
+    }
+
+    #[test]
+    fn field_of_view_is_symmetric() {
+        let grid = map();
+        let floors = grid
+            .iter_new()
+            .filter(|(_, element)| element.is_none())
+            .map(|(coordinate, _)| coordinate)
+            .collect::<Vec<_>>();
+        let views = floors
+            .iter()
+            .map(|floor| {
+                let view = grid
+                    .field_of_view(*floor, 6, DistanceMetric::Euclidean, is_wall)
+                    .unwrap();
+                (*floor, view)
+            })
+            .collect::<HashMap<_, _>>();
+
+        for (from, view) in &views {
+            for to in view.iter().filter(|to| grid.element(*to).is_err()) {
+                assert!(views[to].contains(from), "{from:?} sees {to:?}");
+            }
+        }
+    }
+}
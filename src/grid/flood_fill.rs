@@ -0,0 +1,270 @@
+use super::Grid;
+use crate::Bounds;
+use crate::Connectivity;
+use crate::Coordinate;
+use crate::OutOfBoundsError;
+use crate::bounded::Bounded;
+use crate::bounded::OriginBounded;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+/// A group of connected elements found by [`Grid::connected_components`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Component {
+    size: usize,
+    bounds: Bounds,
+}
+
+impl Component {
+    /// The number of elements in the component.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The smallest bounds that contain every element of the component.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// Count a coordinate as part of the component and grow the bounds to contain it.
+    fn include(&mut self, coordinate: Coordinate) {
+        self.size += 1;
+        self.bounds = Bounds::from_boundaries(
+            self.bounds.x_min_boundary().min(coordinate.x),
+            self.bounds.x_max_boundary().max(coordinate.x),
+            self.bounds.y_min_boundary().min(coordinate.y),
+            self.bounds.y_max_boundary().max(coordinate.y),
+        );
+    }
+}
+
+impl<T> Grid<T> {
+    /// All coordinates that can be reached from the start by moving between adjacent coordinates
+    /// for which the predicate holds.
+    ///
+    /// The start is included if the predicate holds for it. If it does not, the result is empty.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the start is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tudi::Connectivity;
+    /// use tudi::Coordinate;
+    /// use tudi::Grid;
+    /// let walls = HashMap::from([('#', ())]);
+    /// let grid = Grid::from_str_by_map("..#\n.#.\n#..", &walls).unwrap();
+    /// let start = Coordinate { x: -1, y: 1 };
+    /// let is_floor = |_, wall: Option<&()>| wall.is_none();
+    ///
+    /// assert_eq!(grid.flood_fill(start, Connectivity::Manhattan, is_floor).unwrap().len(), 3);
+    /// assert_eq!(grid.flood_fill(start, Connectivity::Euclid, is_floor).unwrap().len(), 6);
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: Coordinate,
+        connectivity: Connectivity,
+        mut predicate: impl FnMut(Coordinate, Option<&T>) -> bool,
+    ) -> Result<HashSet<Coordinate>, OutOfBoundsError> {
+        self.coordinate_to_index(&start)?;
+        let mut filled = HashSet::new();
+        if !predicate(start, self.element_unchecked(&start)) {
+            return Ok(filled);
+        }
+
+        filled.insert(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            for neighbor in self.neighbors_iter(&current, connectivity) {
+                if !filled.contains(&neighbor)
+                    && predicate(neighbor, self.element_unchecked(&neighbor))
+                {
+                    filled.insert(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        Ok(filled)
+    }
+
+    /// Label each group of adjacent elements that are equal according to `eq`.
+    ///
+    /// The returned grid has the bounds of this grid and holds the index of the component of each
+    /// element. Empty coordinates do not belong to any component and stay empty. Components are
+    /// numbered in the order of their first element from west to east, north to south.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tudi::Bounds;
+    /// use tudi::Connectivity;
+    /// use tudi::Grid;
+    /// let tiles = HashMap::from([('a', 'a'), ('b', 'b')]);
+    /// let grid = Grid::from_str_by_map("aab\nb.b\nbbb", &tiles).unwrap();
+    ///
+    /// let (labels, components) = grid.connected_components(Connectivity::Manhattan, |a, b| a == b);
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[0].size(), 2);
+    /// assert_eq!(components[1].size(), 6);
+    /// assert_eq!(components[1].bounds(), Bounds::from_boundaries(-1, 1, -1, 1));
+    /// ```
+    pub fn connected_components(
+        &self,
+        connectivity: Connectivity,
+        mut eq: impl FnMut(&T, &T) -> bool,
+    ) -> (Grid<usize>, Vec<Component>) {
+        let mut labels =
+            Grid::with_count(OriginBounded::x_count(self), OriginBounded::y_count(self))
+                .with_performance_tuning(self.performance_tuning())
+                .with_wrap_mode(self.wrap_mode());
+        let mut components = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for (seed, _) in self.iter_elements_new() {
+            if labels.element_unchecked(&seed).is_some() {
+                continue;
+            }
+
+            let label = components.len();
+            labels
+                .store_element(&seed, label)
+                .expect("the seed is within bounds");
+            let mut component = Component {
+                size: 0,
+                bounds: Bounds::from_boundaries(seed.x, seed.x, seed.y, seed.y),
+            };
+            queue.push_back(seed);
+
+            while let Some(current) = queue.pop_front() {
+                component.include(current);
+                let element = self
+                    .element_unchecked(&current)
+                    .expect("only elements are queued");
+                for neighbor in self.neighbors_iter(&current, connectivity) {
+                    if labels.element_unchecked(&neighbor).is_none()
+                        && self
+                            .element_unchecked(&neighbor)
+                            .is_some_and(|other| eq(element, other))
+                    {
+                        labels
+                            .store_element(&neighbor, label)
+                            .expect("the neighbor is within bounds");
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+
+        (labels, components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+    use crate::PerformanceTuning;
+    use std::collections::HashMap;
+
+    /// A 6x4 grid of 'a' and 'b' tiles with empty coordinates in between.
+    fn tiles(performance_tuning: PerformanceTuning) -> Grid<char> {
+        let tiles = HashMap::from([('a', 'a'), ('b', 'b')]);
+        Grid::from_str_by_map("aa.b.b\na..bb.\n.a....\nbb..aa", &tiles)
+            .unwrap()
+            .with_performance_tuning(performance_tuning)
+    }
+
+    #[test]
+    fn flood_fill_respects_connectivity() {
+        let grid = tiles(PerformanceTuning::Auto);
+        let start = Coordinate { x: -2, y: 2 };
+        let is_a = |_, element: Option<&char>| element == Some(&'a');
+
+        let manhattan = grid
+            .flood_fill(start, Connectivity::Manhattan, is_a)
+            .unwrap();
+        assert_eq!(
+            manhattan,
+            HashSet::from([
+                start,
+                Coordinate { x: -1, y: 2 },
+                Coordinate { x: -2, y: 1 }
+            ])
+        );
+
+        let euclid = grid.flood_fill(start, Connectivity::Euclid, is_a).unwrap();
+        assert_eq!(euclid.len(), 4);
+        assert!(euclid.contains(&Coordinate { x: -1, y: 0 }));
+    }
+
+    #[test]
+    fn flood_fill_from_unmatched_start() {
+        let grid = tiles(PerformanceTuning::Auto);
+        let is_a = |_, element: Option<&char>| element == Some(&'a');
+        assert!(
+            grid.flood_fill(Coordinate { x: 0, y: 0 }, Connectivity::Euclid, is_a)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            grid.flood_fill(Coordinate { x: 4, y: 0 }, Connectivity::Euclid, is_a)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn flood_fill_covers_large_grids() {
+        let grid: Grid<()> = Grid::with_count(
+            AxisCount::from_u64_unchecked(1000),
+            AxisCount::from_u64_unchecked(500),
+        );
+        let filled = grid
+            .flood_fill(Coordinate::default(), Connectivity::Manhattan, |_, _| true)
+            .unwrap();
+        assert_eq!(filled.len(), 500_000);
+    }
+
+    #[test]
+    fn connected_components_manhattan() {
+        for tuning in [PerformanceTuning::Memory, PerformanceTuning::Speed] {
+            let grid = tiles(tuning);
+            let (labels, components) = grid.connected_components(Connectivity::Manhattan, char::eq);
+
+            assert_eq!(labels.bounds(), grid.bounds());
+            assert!(labels.same_shape_and_occupancy(&grid));
+            assert_eq!(
+                components.iter().map(Component::size).collect::<Vec<_>>(),
+                [3, 3, 1, 1, 2, 2]
+            );
+            assert_eq!(components[1].bounds(), Bounds::from_boundaries(1, 2, 1, 2));
+            assert_eq!(labels.element(&Coordinate { x: -2, y: 1 }), Ok(&0));
+            assert_eq!(labels.element(&Coordinate { x: -1, y: 0 }), Ok(&3));
+        }
+    }
+
+    #[test]
+    fn connected_components_euclid() {
+        let grid = tiles(PerformanceTuning::Auto);
+        let (labels, components) = grid.connected_components(Connectivity::Euclid, char::eq);
+        assert_eq!(
+            components.iter().map(Component::size).collect::<Vec<_>>(),
+            [4, 4, 2, 2]
+        );
+        assert_eq!(labels.element(&Coordinate { x: -1, y: 0 }), Ok(&0));
+        assert_eq!(
+            components[0].bounds(),
+            Bounds::from_boundaries(-2, -1, 0, 2)
+        );
+
+        let (_, components) = grid.connected_components(Connectivity::Euclid, |_, _| true);
+        assert_eq!(
+            components.iter().map(Component::size).collect::<Vec<_>>(),
+            [6, 4, 2]
+        );
+    }
+}
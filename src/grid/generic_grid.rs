@@ -1,23 +1,38 @@
 use super::Grid;
 use super::grid_coordinate::GridCoordinate;
+use super::grid_iter::GridIntoIter;
 use super::grid_iter::GridIter;
+use super::grid_iter::GridIterMut;
+use super::grid_storage::GridStorage;
+use super::grid_storage::cell_count;
 use super::performance_tuning::PerformanceTuning;
+use super::speed_cache::SpeedCache;
 use crate::AbsoluteDirection;
 use crate::AxisCount;
+use crate::AxisCountError;
 use crate::BoundedMovingObject;
 use crate::Bounds;
+use crate::Connectivity;
 use crate::Coordinate;
 use crate::GridError;
+use crate::HorizontalDirection;
 use crate::OriginCenteredBounds;
 use crate::OutOfBoundsError;
 use crate::Positioned;
 use crate::VerticalDirection;
+use crate::WrapMode;
 use crate::bounded::Bounded;
 use crate::bounded::OriginBounded;
 use crate::bounded::OriginCentered;
 use crate::bounded::OriginCenteredness;
 use crate::grid::GridCreationError;
-use itertools::iproduct;
+use itertools::Either;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Index;
+use std::ops::IndexMut;
 
 impl<T> Grid<T> {
     ///Create a rectangular grid with empty elements.
@@ -57,29 +72,17 @@ impl<T> Grid<T> {
     pub fn new(x_count: usize, y_count: usize) -> Self {
         let x_count = AxisCount::from_len(u32::try_from(x_count).unwrap() - 1);
         let y_count = AxisCount::from_len(u32::try_from(y_count).unwrap() - 1);
-        let bounds = OriginCenteredBounds::new(x_count, y_count);
-        let mut result = Self {
-            grid_data: Vec::new(),
-            bounds,
-            performance_tuning: PerformanceTuning::Auto,
-        };
-
-        for (y, x) in iproduct!(
-            (result.y_min_boundary()..=result.y_max_boundary()).rev(),
-            result.x_min_boundary()..=result.x_max_boundary()
-        ) {
-            result
-                .grid_data
-                .push(GridCoordinate::Empty(Coordinate { x, y }));
-        }
-        result
+        Self::with_count(x_count, y_count)
     }
 
     /// Create an empty grid with a given x- and y-count.
     ///
+    /// The grid uses [`PerformanceTuning::Auto`]. See also
+    /// [`with_performance_tuning`](Grid::with_performance_tuning()).
+    ///
     /// # Panics
     ///
-    /// This method panics if the allocation, roughly
+    /// This method panics if the grid is stored densely and the allocation, roughly
     /// `x_count * y_count * max(size_of::<T>(), size_of::<Coordinate>())`,
     /// exceeds `isize::MAX` bytes.
     ///
@@ -98,25 +101,202 @@ impl<T> Grid<T> {
     ///
     ///```
     pub fn with_count(x_count: AxisCount, y_count: AxisCount) -> Self {
-        let cell_count = x_count.as_u64() * y_count.as_u64();
-        let cell_count =
-            usize::try_from(cell_count).expect("cell count is greater than usize::MAX");
-
-        let mut grid_data: Vec<GridCoordinate<T>> = Vec::with_capacity(cell_count);
-
         let bounds = OriginCenteredBounds::new(x_count, y_count);
+        let performance_tuning = PerformanceTuning::Auto;
 
-        for (y, x) in iproduct!(
-            (bounds.y_min_boundary()..=bounds.y_max_boundary()).rev(),
-            bounds.x_min_boundary()..=bounds.x_max_boundary()
-        ) {
-            grid_data.push(GridCoordinate::Empty(Coordinate { x, y }));
-        }
+        let storage = if performance_tuning.prefers_dense(cell_count(&bounds), 0, false) {
+            GridStorage::dense(&bounds)
+        } else {
+            GridStorage::sparse()
+        };
 
         Self {
-            grid_data,
+            storage,
             bounds,
-            performance_tuning: PerformanceTuning::Auto,
+            performance_tuning,
+            wrap_mode: WrapMode::default(),
+            speed_cache: None,
+        }
+    }
+
+    /// Use a [`PerformanceTuning`] for this grid.
+    ///
+    /// The elements of the grid are kept; only the way they are stored changes.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuning requires dense storage and the allocation, roughly
+    /// `x_count * y_count * max(size_of::<T>(), size_of::<Coordinate>())`,
+    /// exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tudi::AxisCount;
+    /// use tudi::Coordinate;
+    /// use tudi::Grid;
+    /// use tudi::PerformanceTuning;
+    ///
+    /// // A 100_000 x 100_000 grid only allocates memory for its elements.
+    /// let count = AxisCount::try_from(100_000).unwrap();
+    /// let mut grid = Grid::with_count(count, count).with_performance_tuning(PerformanceTuning::Memory);
+    /// grid.store_element(&Coordinate { x: 40_000, y: -40_000 }, 'x').unwrap();
+    ///
+    /// assert_eq!(grid.element(&Coordinate { x: 40_000, y: -40_000 }), Ok(&'x'));
+    /// assert_eq!(grid.iter_elements_new().count(), 1);
+    /// ```
+    pub fn with_performance_tuning(mut self, performance_tuning: PerformanceTuning) -> Self {
+        self.set_performance_tuning(performance_tuning);
+        self
+    }
+
+    /// The [`PerformanceTuning`] of this grid.
+    pub fn performance_tuning(&self) -> PerformanceTuning {
+        self.performance_tuning
+    }
+
+    /// Change the [`PerformanceTuning`] of this grid.
+    ///
+    /// See also [`with_performance_tuning`](Grid::with_performance_tuning()).
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuning requires dense storage and the allocation, roughly
+    /// `x_count * y_count * max(size_of::<T>(), size_of::<Coordinate>())`,
+    /// exceeds `isize::MAX` bytes.
+    pub fn set_performance_tuning(&mut self, performance_tuning: PerformanceTuning) {
+        self.performance_tuning = performance_tuning;
+        self.apply_performance_tuning();
+    }
+
+    /// Use a [`WrapMode`] for this grid.
+    ///
+    /// A toroidal grid wraps coordinates that are out of bounds around to the opposite border in
+    /// [`element`](Grid::element()), [`element_unchecked`](Grid::element_unchecked()),
+    /// [`get_mut_element`](Grid::get_mut_element()), [`store_element`](Grid::store_element()),
+    /// [`remove_element`](Grid::remove_element()),
+    /// [`move_element_in_direction`](Grid::move_element_in_direction()) and
+    /// [`neighbors`](Grid::neighbors()), instead of treating them as out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::WrapMode;
+    /// use tudi::grid;
+    /// let mut board = grid!(5, 3).with_wrap_mode(WrapMode::Toroidal);
+    /// board.store_element(&Coordinate { x: 3, y: 0 }, "pac-man").unwrap();
+    ///
+    /// assert_eq!(board.element(&Coordinate { x: -2, y: 0 }), Ok(&"pac-man"));
+    /// assert_eq!(board.element(&Coordinate { x: 8, y: 3 }), Ok(&"pac-man"));
+    /// ```
+    pub fn with_wrap_mode(mut self, wrap_mode: WrapMode) -> Self {
+        self.set_wrap_mode(wrap_mode);
+        self
+    }
+
+    /// The [`WrapMode`] of this grid.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.wrap_mode
+    }
+
+    /// Change the [`WrapMode`] of this grid.
+    ///
+    /// See also [`with_wrap_mode`](Grid::with_wrap_mode()).
+    pub fn set_wrap_mode(&mut self, wrap_mode: WrapMode) {
+        self.wrap_mode = wrap_mode;
+    }
+
+    /// The coordinate that the methods of this grid use for a position: the wrapped coordinate
+    /// for a toroidal grid, and the position itself otherwise.
+    fn resolve<C: Positioned>(&self, coordinate: &C) -> Coordinate {
+        match self.wrap_mode {
+            WrapMode::Bounded => *coordinate.position(),
+            WrapMode::Toroidal => self.wrap(coordinate),
+        }
+    }
+
+    /// Switch between dense and sparse storage if the performance tuning prefers the other
+    /// representation, and keep the speed cache in sync with the tuning and the bounds.
+    ///
+    /// This has to be called whenever the bounds or the number of elements change.
+    pub(super) fn apply_performance_tuning(&mut self) {
+        let is_dense = self.storage.is_dense();
+        let prefers_dense = self.performance_tuning.prefers_dense(
+            cell_count(&self.bounds),
+            self.storage.len(),
+            is_dense,
+        );
+
+        if prefers_dense && !is_dense {
+            self.storage.make_dense(&self.bounds);
+        } else if !prefers_dense && is_dense {
+            self.storage.make_sparse(&self.bounds);
+        }
+
+        if self.performance_tuning != PerformanceTuning::Speed {
+            self.speed_cache = None;
+        } else if !self
+            .speed_cache
+            .as_ref()
+            .is_some_and(|cache| cache.is_built_for(&self.bounds))
+        {
+            let bounds = self.bounds;
+            let storage = &self.storage;
+            self.speed_cache = Some(SpeedCache::new(bounds, |index| {
+                let coordinate = bounds
+                    .index_to_coordinate(index)
+                    .expect("every index below the cell count is within bounds");
+                storage.get(index, coordinate).is_some()
+            }));
+        }
+    }
+
+    /// The region as [`Bounds`], if it is within the bounds of the grid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first corner of the region, northwest or southeast, that is out of
+    /// bounds.
+    pub(super) fn check_region<B: Bounded>(&self, region: &B) -> Result<Bounds, OutOfBoundsError> {
+        for corner in [
+            Coordinate {
+                x: region.x_min_boundary(),
+                y: region.y_max_boundary(),
+            },
+            Coordinate {
+                x: region.x_max_boundary(),
+                y: region.y_min_boundary(),
+            },
+        ] {
+            if let Some((first_direction, second_direction)) =
+                self.out_of_bounds_directions(&corner)
+            {
+                return Err(OutOfBoundsError::new(
+                    corner,
+                    first_direction,
+                    second_direction,
+                ));
+            }
+        }
+
+        Ok(Bounds::from_boundaries(
+            region.x_min_boundary(),
+            region.x_max_boundary(),
+            region.y_min_boundary(),
+            region.y_max_boundary(),
+        ))
+    }
+
+    /// The index of a coordinate in the storage. Uses the precomputed row offsets of the speed
+    /// cache if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate is out of bounds.
+    fn index_of<C: Positioned>(&self, coordinate: &C) -> Result<u64, OutOfBoundsError> {
+        match &self.speed_cache {
+            Some(cache) => cache.coordinate_to_index(coordinate),
+            None => self.coordinate_to_index(coordinate),
         }
     }
 
@@ -156,7 +336,7 @@ impl<T> Grid<T> {
     ///
     /// # Panics
     ///
-    /// This method panics if the coordinate is out of bounds.
+    /// This method panics if the coordinate is out of bounds and the grid is not toroidal.
     ///
     /// # Examples
     /// ```
@@ -171,16 +351,10 @@ impl<T> Grid<T> {
     ///
     /// ```
     pub fn element_unchecked<C: Positioned>(&self, coordinate: &C) -> Option<&T> {
-        assert!(self.is_within_bounds(coordinate));
-        let index = self.coordinate_to_index(coordinate).unwrap();
-        let val = &self.grid_data[usize::try_from(index)
-            .expect("since the coordinate is within bounds, it also fits in a usize")];
-
-        if let GridCoordinate::Object(element) = val {
-            Some(element)
-        } else {
-            None
-        }
+        let coordinate = self.resolve(coordinate);
+        assert!(self.is_within_bounds(&coordinate));
+        let index = self.index_of(&coordinate).unwrap();
+        self.storage.get(index, coordinate)
     }
 
     /// A reference to an element in the grid.
@@ -192,6 +366,7 @@ impl<T> Grid<T> {
     /// This method returns an error if the provided position does not contain an element.
     ///
     pub fn element<C: Positioned>(&self, coordinate: &C) -> Result<&T, GridError> {
+        let coordinate = &self.resolve(coordinate);
         if let Some((first_direction, second_direction)) = self.out_of_bounds_directions(coordinate)
         {
             Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
@@ -213,16 +388,12 @@ impl<T> Grid<T> {
     ///
     /// This method returns an error if the provided position is out of bounds.
     pub fn get_mut_element<C: Positioned>(&mut self, coordinate: &C) -> Result<&mut T, GridError> {
-        let index = self.coordinate_to_index(coordinate)?;
+        let coordinate = &self.resolve(coordinate);
+        let index = self.index_of(coordinate)?;
 
-        let val = &mut self.grid_data[usize::try_from(index)
-            .expect("since the coordinate is within bounds, it also fits in a usize")];
-
-        if let GridCoordinate::Object(element) = val {
-            Ok(element)
-        } else {
-            Err(GridError::UnoccupiedError(*coordinate.position()))
-        }
+        self.storage
+            .get_mut(index, *coordinate.position())
+            .ok_or(GridError::UnoccupiedError(*coordinate.position()))
     }
 
     /// Store an element at a coordinate.
@@ -241,16 +412,13 @@ impl<T> Grid<T> {
         coordinate: &C,
         element: T,
     ) -> Result<Option<T>, GridError> {
-        let index = self.coordinate_to_index(coordinate)?;
-        let previous_val = std::mem::replace(
-            &mut self.grid_data[usize::try_from(index)
-                .expect("since index is within bounds it must fit in usize")],
-            GridCoordinate::Object(element),
-        );
-        match previous_val {
-            GridCoordinate::Object(val) => Ok(Some(val)),
-            GridCoordinate::Empty(_) => Ok(None),
+        let previous_val = self.store_element_without_tuning(coordinate, element)?;
+
+        if previous_val.is_none() {
+            self.apply_performance_tuning();
         }
+
+        Ok(previous_val)
     }
 
     /// Remove an element, leaving an empty coordinate in its place.
@@ -261,54 +429,84 @@ impl<T> Grid<T> {
     ///
     //  Returns an error if there is no element at a coordinate at which this function is called.
     pub fn remove_element<C: Positioned>(&mut self, coordinate: &C) -> Result<T, GridError> {
-        let index = self.coordinate_to_index(coordinate)?;
-        let previous_grid_coordinate = std::mem::replace(
-            &mut self.grid_data[usize::try_from(index)
-                .expect("since index stems from the grid, it should fit in a usize")],
-            GridCoordinate::Empty(*coordinate.position()),
-        );
-
-        if let GridCoordinate::Object(val) = previous_grid_coordinate {
-            self.grid_data[usize::try_from(index)
-                .expect("since index stems from the grid, it should fit in a usize")] =
-                GridCoordinate::Empty(*coordinate.position());
-            Ok(val)
-        } else {
-            Err(GridError::UnoccupiedError(*coordinate.position()))
-        }
+        let val = self.remove_element_without_tuning(coordinate)?;
+        self.apply_performance_tuning();
+        Ok(val)
     }
 
-    pub fn iter_new(&self) -> GridIter<'_, T> {
-        match self.performance_tuning {
-            PerformanceTuning::Auto => self.iter_new_memory(),
-
-            PerformanceTuning::Speed => self.iter_new_speed(),
+    /// Same as [`Self::store_element`], but the storage keeps its representation. Batches of
+    /// changes use this and apply the performance tuning once at the end, so that the storage is
+    /// not converted back and forth in the middle of the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate argument is out of bounds.
+    pub(super) fn store_element_without_tuning<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        element: T,
+    ) -> Result<Option<T>, GridError> {
+        let coordinate = &self.resolve(coordinate);
+        let index = self.index_of(coordinate)?;
+        let previous_val = self.storage.insert(index, *coordinate.position(), element);
 
-            PerformanceTuning::Memory => self.iter_new_memory(),
+        if previous_val.is_none()
+            && let Some(cache) = &mut self.speed_cache
+        {
+            cache.set_occupied(index, true);
         }
+
+        Ok(previous_val)
     }
 
-    fn iter_new_memory(&self) -> GridIter<'_, T> {
-        GridIter::new(self)
+    /// Same as [`Self::remove_element`], but the storage keeps its representation. See
+    /// [`Self::store_element_without_tuning`].
+    ///
+    /// # Errors
+    ///
+    /// Return an error if the coordinate argument is out of bounds, or if there is no element at
+    /// the coordinate.
+    pub(super) fn remove_element_without_tuning<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+    ) -> Result<T, GridError> {
+        let coordinate = &self.resolve(coordinate);
+        let index = self.index_of(coordinate)?;
+        let val = self
+            .storage
+            .remove(index, *coordinate.position())
+            .ok_or(GridError::UnoccupiedError(*coordinate.position()))?;
+
+        if let Some(cache) = &mut self.speed_cache {
+            cache.set_occupied(index, false);
+        }
+        Ok(val)
     }
 
-    fn iter_new_speed(&self) -> GridIter<'_, T> {
+    pub fn iter_new(&self) -> GridIter<'_, T> {
         GridIter::new(self)
     }
 
-    pub fn iter_mut_new(&mut self) -> impl Iterator<Item = (Coordinate, Option<&mut T>)> {
-        let coordinates = (0..OriginBounded::x_count(&self).as_u64()
-            * OriginBounded::y_count(&self).as_u64())
-            .flat_map(|x| self.index_to_coordinate(x))
-            .collect::<Vec<Coordinate>>();
+    pub fn iter_mut_new(&mut self) -> GridIterMut<'_, T> {
+        GridIterMut::new(self)
+    }
 
-        self.grid_data
-            .iter_mut()
-            .enumerate()
-            .map(move |(index, grid_coordinate)| match grid_coordinate {
-                GridCoordinate::Object(val) => (coordinates[index], Some(val)),
-                GridCoordinate::Empty(_) => (coordinates[index], None),
-            })
+    /// Store every element of an iterator at its coordinate, replacing any previous element.
+    ///
+    /// This is the fallible version of [`Extend::extend`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error at the first coordinate that is out of bounds. The elements
+    /// before it have been stored.
+    pub fn try_extend<I: IntoIterator<Item = (Coordinate, T)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), GridError> {
+        for (coordinate, element) in iter {
+            self.store_element(&coordinate, element)?;
+        }
+        Ok(())
     }
 
     pub fn iter_mut_elements_new(&mut self) -> impl Iterator<Item = (Coordinate, &mut T)> {
@@ -316,14 +514,29 @@ impl<T> Grid<T> {
     }
 
     pub fn iter_elements_new(&self) -> impl Iterator<Item = (Coordinate, &T)> {
-        self.iter_new()
-            .filter_map(|(coordinate, element)| element.map(|element| (coordinate, element)))
+        match &self.speed_cache {
+            Some(cache) => Either::Left(cache.occupied_indices().map(|index| {
+                let coordinate = cache.coordinates()[index as usize];
+                let element = self
+                    .storage
+                    .get(index, coordinate)
+                    .expect("the occupancy bitmap is in sync with the storage");
+                (coordinate, element)
+            })),
+            None => Either::Right(self.storage.elements(&self.bounds)),
+        }
     }
 
     /// A vec of all empty rows.
     ///
     /// It starts at the bottom (with negative indices).
     pub fn empty_rows(&self) -> Vec<i32> {
+        if let Some(cache) = &self.speed_cache {
+            return (self.y_min_boundary()..=self.y_max_boundary())
+                .filter(|y| !cache.row_is_occupied(*y))
+                .collect();
+        }
+
         let mut result: Vec<i32> = Vec::new();
         for y in self.y_min_boundary()..=self.y_max_boundary() {
             'inner: for x in self.x_min_boundary()..=self.x_max_boundary() {
@@ -340,6 +553,19 @@ impl<T> Grid<T> {
 
     /// A vec of all empty columns.
     pub fn empty_columns(&self) -> Vec<i32> {
+        if self.speed_cache.is_some() {
+            let x_min = self.x_min_boundary();
+            let mut occupied_columns = vec![false; self.x_count().as_u64() as usize];
+            for (coordinate, _) in self.iter_elements_new() {
+                occupied_columns[coordinate.x.abs_diff(x_min) as usize] = true;
+            }
+
+            return (x_min..=self.x_max_boundary())
+                .zip(occupied_columns)
+                .filter_map(|(x, occupied)| (!occupied).then_some(x))
+                .collect();
+        }
+
         let mut result: Vec<i32> = Vec::new();
         for x in self.x_min_boundary()..=self.x_max_boundary() {
             'inner: for y in self.y_min_boundary()..=self.y_max_boundary() {
@@ -356,6 +582,9 @@ impl<T> Grid<T> {
 
     /// Move all elements above or on the provided row in the provided direction.
     ///
+    /// The elements move at once, and wrap around the borders of a toroidal grid. The grid is
+    /// unchanged if an error is returned.
+    ///
     /// # Errors
     ///
     /// This method returns an error in case of out of bounds or collision.
@@ -369,6 +598,9 @@ impl<T> Grid<T> {
 
     /// Move all elements below or on the provided row in the provided direction.
     ///
+    /// The elements move at once, and wrap around the borders of a toroidal grid. The grid is
+    /// unchanged if an error is returned.
+    ///
     /// # Errors
     ///
     /// This method returns an error in case of out of bounds or collision.
@@ -380,6 +612,46 @@ impl<T> Grid<T> {
         self.row_filter_move_elements_in_direction(Coordinate::is_below_row, y_coord, direction)
     }
 
+    /// Move all elements left of or on the provided column in the provided direction.
+    ///
+    /// The elements move at once, and wrap around the borders of a toroidal grid. The grid is
+    /// unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in case of out of bounds or collision.
+    pub fn move_elements_left_of_column_in_direction(
+        &mut self,
+        x_coord: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        self.column_filter_move_elements_in_direction(
+            Coordinate::is_left_of_column,
+            x_coord,
+            direction,
+        )
+    }
+
+    /// Move all elements right of or on the provided column in the provided direction.
+    ///
+    /// The elements move at once, and wrap around the borders of a toroidal grid. The grid is
+    /// unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in case of out of bounds or collision.
+    pub fn move_elements_right_of_column_in_direction(
+        &mut self,
+        x_coord: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        self.column_filter_move_elements_in_direction(
+            Coordinate::is_right_of_column,
+            x_coord,
+            direction,
+        )
+    }
+
     /// The coordinate count along the x-dimension.
     /// # Examples
     /// ```
@@ -407,8 +679,55 @@ impl<T> Grid<T> {
         OriginBounded::y_count(self)
     }
 
+    /// The adjacent coordinates of a position within the grid.
+    ///
+    /// Neighbors that are out of bounds are left out, unless the grid is
+    /// [toroidal](WrapMode::Toroidal). Then they wrap around to the opposite border, and a grid
+    /// that is less than three coordinates wide can yield the same neighbor, or the position
+    /// itself, more than once.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Connectivity;
+    /// use tudi::Coordinate;
+    /// use tudi::WrapMode;
+    /// use tudi::grid;
+    /// let grid: tudi::Grid<()> = grid!(5, 5);
+    /// let corner = Coordinate { x: 2, y: 2 };
+    /// assert_eq!(grid.neighbors(&corner, Connectivity::Euclid).len(), 3);
+    ///
+    /// let torus = grid.with_wrap_mode(WrapMode::Toroidal);
+    /// let neighbors = torus.neighbors(&corner, Connectivity::Manhattan);
+    /// assert_eq!(neighbors.len(), 4);
+    /// assert!(neighbors.contains(&Coordinate { x: -2, y: 2 }));
+    /// assert!(neighbors.contains(&Coordinate { x: 2, y: -2 }));
+    /// ```
+    pub fn neighbors<C: Positioned>(
+        &self,
+        coordinate: &C,
+        connectivity: Connectivity,
+    ) -> Vec<Coordinate> {
+        self.neighbors_iter(coordinate, connectivity).collect()
+    }
+
+    /// Same as [`Self::neighbors`], without collecting the neighbors.
+    pub(super) fn neighbors_iter<'a, C: Positioned>(
+        &'a self,
+        coordinate: &C,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = Coordinate> + use<'a, C, T> {
+        let coordinate = self.resolve(coordinate);
+        match self.wrap_mode {
+            WrapMode::Bounded => Either::Left(connectivity.bounded_neighbors(self, coordinate)),
+            WrapMode::Toroidal => Either::Right(connectivity.wrapping_neighbors(self, coordinate)),
+        }
+    }
+
     /// Move an element within the grid in a direction.
     ///
+    /// On a [toroidal](WrapMode::Toroidal) grid, an element that is moved past a border continues
+    /// from the opposite border.
+    ///
     /// # Errors
     ///
     /// This method returns an error if the resulting move would be out of bounds.
@@ -421,16 +740,23 @@ impl<T> Grid<T> {
         coordinate: &Coordinate,
         direction: AbsoluteDirection,
     ) -> Result<Coordinate, GridError> {
+        let coordinate = &self.resolve(coordinate);
         let mut marker = BoundedMovingObject::try_from((&self, coordinate))?;
 
-        if marker.move_in_absolute_direction(direction, 1) {
+        if marker.move_in_absolute_direction_with(direction, 1, self.wrap_mode) {
             if self.element_unchecked(marker.position()).is_some() {
                 return Err(GridError::CollisionError);
             }
 
-            let element = self.remove_element(coordinate)?;
-            self.store_element(marker.position(), element)?;
+            let element = self.remove_element_without_tuning(coordinate)?;
+            self.store_element_without_tuning(marker.position(), element)?;
+            self.apply_performance_tuning();
             Ok(*marker.position())
+        } else if self.wrap_mode == WrapMode::Toroidal {
+            // The grid is a single coordinate wide along the direction, so the element wraps
+            // around onto itself.
+            self.element(coordinate)?;
+            Ok(*coordinate)
         } else {
             Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
                 marker.coordinate_in_direction(direction, 1),
@@ -482,6 +808,188 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Expand the grid at a column while keeping it origin-centered.
+    /// You can think of `x_coord` as an indicator of which elements are pushed.
+    /// This, along with if the grid is odd or even numbered, determines which elements are moved.
+    /// The movement of each element are as follows:
+    ///
+    /// |Element pos      | Even #columns | Odd #columns |
+    /// |-----------------|---------------|--------------|
+    /// | **right of x**  | no change     | moved right  |
+    /// |    **at x**     | moved left    | moved right  |
+    /// | **left of x**   | moved left    | no change    |
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided x-coordinate is out of bounds.
+    pub fn expand_at_column(&mut self, x_coord: i32) -> Result<HorizontalDirection, GridError> {
+        if x_coord > self.x_max_boundary() || x_coord < self.x_min_boundary() {
+            return Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: x_coord, y: 0 },
+                if x_coord > self.x_max_boundary() {
+                    AbsoluteDirection::East
+                } else {
+                    AbsoluteDirection::West
+                },
+                None,
+            )));
+        };
+
+        let east_add = self.add_column();
+
+        if east_add {
+            #[expect(clippy::missing_panics_doc)]
+            self.move_elements_right_of_column_in_direction(x_coord, AbsoluteDirection::East)
+                .expect("Adding an east column provides space to move elements east");
+            Ok(HorizontalDirection::East)
+        } else {
+            #[expect(clippy::missing_panics_doc)]
+            self.move_elements_left_of_column_in_direction(x_coord, AbsoluteDirection::West)
+                .expect("Adding a west column provides space to move elements west");
+            Ok(HorizontalDirection::West)
+        }
+    }
+
+    /// Remove a row while keeping the grid origin-centered, and return the removed elements from
+    /// west to east. The remaining elements are moved the way [`Self::expand_at_row`] moves them,
+    /// in reverse:
+    ///
+    /// |Element pos | Even #rows | Odd #rows  |
+    /// |------------|------------|------------|
+    /// | **above y**    | moved down | no change  |
+    /// | **below y**    | no change  | moved up   |
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided y-coordinate is out of bounds, or if the grid
+    /// only has one row.
+    pub fn remove_row(&mut self, y_coord: i32) -> Result<Vec<(Coordinate, T)>, GridError> {
+        if y_coord > self.y_max_boundary() || y_coord < self.y_min_boundary() {
+            return Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: 0, y: y_coord },
+                if y_coord > self.y_max_boundary() {
+                    AbsoluteDirection::North
+                } else {
+                    AbsoluteDirection::South
+                },
+                None,
+            )));
+        };
+
+        if OriginBounded::y_count(&self).as_u64() == 1 {
+            return Err(GridError::MinimumSizeError);
+        }
+
+        let removed = self.remove_elements_where(|c| c.y == y_coord);
+
+        let north_removal = OriginBounded::y_count(&self).as_u64().is_multiple_of(2);
+        #[expect(clippy::missing_panics_doc)]
+        if north_removal && y_coord < self.y_max_boundary() {
+            self.move_elements_above_row_in_direction(y_coord + 1, AbsoluteDirection::South)
+                .expect("The removed row provides space to move elements south");
+        } else if !north_removal && y_coord > self.y_min_boundary() {
+            self.move_elements_below_row_in_direction(y_coord - 1, AbsoluteDirection::North)
+                .expect("The removed row provides space to move elements north");
+        }
+
+        let x_count = OriginBounded::x_count(&self).as_u64() as usize;
+        if let Some(cells) = self.storage.dense_cells_mut() {
+            if north_removal {
+                cells.drain(..x_count);
+            } else {
+                cells.truncate(cells.len() - x_count);
+            }
+        }
+        self.bounds.shrink_bounds_vertically();
+        self.apply_performance_tuning();
+
+        Ok(removed)
+    }
+
+    /// Remove a column while keeping the grid origin-centered, and return the removed elements
+    /// from north to south. The remaining elements are moved the way [`Self::expand_at_column`]
+    /// moves them, in reverse:
+    ///
+    /// |Element pos      | Even #columns | Odd #columns |
+    /// |-----------------|---------------|--------------|
+    /// | **right of x**  | moved left    | no change    |
+    /// | **left of x**   | no change     | moved right  |
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided x-coordinate is out of bounds, or if the grid
+    /// only has one column.
+    pub fn remove_column(&mut self, x_coord: i32) -> Result<Vec<(Coordinate, T)>, GridError> {
+        if x_coord > self.x_max_boundary() || x_coord < self.x_min_boundary() {
+            return Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: x_coord, y: 0 },
+                if x_coord > self.x_max_boundary() {
+                    AbsoluteDirection::East
+                } else {
+                    AbsoluteDirection::West
+                },
+                None,
+            )));
+        };
+
+        if OriginBounded::x_count(&self).as_u64() == 1 {
+            return Err(GridError::MinimumSizeError);
+        }
+
+        let removed = self.remove_elements_where(|c| c.x == x_coord);
+
+        let east_removal = OriginBounded::x_count(&self).as_u64().is_multiple_of(2);
+        #[expect(clippy::missing_panics_doc)]
+        if east_removal && x_coord < self.x_max_boundary() {
+            self.move_elements_right_of_column_in_direction(x_coord + 1, AbsoluteDirection::West)
+                .expect("The removed column provides space to move elements west");
+        } else if !east_removal && x_coord > self.x_min_boundary() {
+            self.move_elements_left_of_column_in_direction(x_coord - 1, AbsoluteDirection::East)
+                .expect("The removed column provides space to move elements east");
+        }
+
+        let x_count = OriginBounded::x_count(&self).as_u64() as usize;
+        if let Some(cells) = self.storage.dense_cells_mut() {
+            let removed_column = if east_removal { x_count - 1 } else { 0 };
+            let mut index = 0;
+            cells.retain(|_| {
+                let keep = index % x_count != removed_column;
+                index += 1;
+                keep
+            });
+        }
+        self.bounds.shrink_bounds_horizontally();
+        self.apply_performance_tuning();
+
+        Ok(removed)
+    }
+
+    /// Remove and return all elements at coordinates that pass the filter, from west to east,
+    /// north to south.
+    ///
+    /// The performance tuning is not applied; the caller applies it once it is done changing the
+    /// grid.
+    fn remove_elements_where(
+        &mut self,
+        filter: impl Fn(&Coordinate) -> bool,
+    ) -> Vec<(Coordinate, T)> {
+        let element_coordinates = self
+            .iter_elements_new()
+            .map(|(coordinate, _)| coordinate)
+            .filter(|c| filter(c))
+            .collect::<Vec<Coordinate>>();
+
+        element_coordinates
+            .into_iter()
+            .map(|coordinate| {
+                let element = self
+                    .remove_element_without_tuning(&coordinate)
+                    .expect("the coordinate was just found to be occupied");
+                (coordinate, element)
+            })
+            .collect()
+    }
+
     /// Add an empty row to the grid.
     ///
     /// If the grid has an even number of rows it always has one more positive row than negative row, and if the grid has an odd number of rows the positive and negative number of rows are equal. This method preserves this property. If the row is added to the top it returns true otherwise it returns false.
@@ -520,11 +1028,13 @@ impl<T> Grid<T> {
     /// the centering around the origin.
     fn add_bottom_row(&mut self) {
         let y_min = self.y_min_boundary() - 1;
-        for x in self.x_min_boundary()..=self.x_max_boundary() {
-            self.grid_data
-                .push(GridCoordinate::Empty(Coordinate { x, y: y_min }));
+        if let Some(cells) = self.storage.dense_cells_mut() {
+            for x in self.bounds.x_min_boundary()..=self.bounds.x_max_boundary() {
+                cells.push(GridCoordinate::Empty(Coordinate { x, y: y_min }));
+            }
         }
         self.bounds.expand_bounds_vertically();
+        self.apply_performance_tuning();
     }
 
     /// Adds an empty top row to the grid. The reason that this function isn't public is because
@@ -534,11 +1044,105 @@ impl<T> Grid<T> {
     /// the centering around the origin.
     fn add_top_row(&mut self) {
         let y_max = self.y_max_boundary() + 1;
-        for x in (self.x_min_boundary()..=self.x_max_boundary()).rev() {
-            self.grid_data
-                .insert(0, GridCoordinate::Empty(Coordinate { x, y: y_max }));
+        if let Some(cells) = self.storage.dense_cells_mut() {
+            for x in (self.bounds.x_min_boundary()..=self.bounds.x_max_boundary()).rev() {
+                cells.insert(0, GridCoordinate::Empty(Coordinate { x, y: y_max }));
+            }
         }
         self.bounds.expand_bounds_vertically();
+        self.apply_performance_tuning();
+    }
+
+    /// Add an empty column to the grid.
+    ///
+    /// If the grid has an even number of columns it always has one more positive column than negative column, and if the grid has an odd number of columns the positive and negative number of columns are equal. This method preserves this property. If the column is added to the east it returns true otherwise it returns false.
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Bounded;
+    /// let mut grid: tudi::Grid<()> = grid!(5, 5);
+    ///
+    /// // since the grid has an odd number of columns, a new column is added to the east.
+    /// assert_eq!(grid.x_max_boundary(), 2);
+    /// assert_eq!(grid.x_min_boundary(), -2);
+    /// grid.add_column();
+    /// assert_eq!(grid.x_max_boundary(), 3);
+    /// assert_eq!(grid.x_min_boundary(), -2);
+    ///
+    /// grid.add_column();
+    /// assert_eq!(grid.x_max_boundary(), 3);
+    /// assert_eq!(grid.x_min_boundary(), -3);
+    ///
+    /// ```
+    pub fn add_column(&mut self) -> bool {
+        if OriginBounded::x_count(&self).as_u64().is_multiple_of(2) {
+            self.add_west_column();
+            false
+        } else {
+            self.add_east_column();
+            true
+        }
+    }
+
+    /// Adds an empty west column to the grid. The reason that this function isn't public is
+    /// because the grid always maintains the origin as its center with a bias toward the positive
+    /// coordinates and that assumption would break if this function were public.
+    /// This function should only be called when the number of columns is even, in order to
+    /// maintain the centering around the origin.
+    fn add_west_column(&mut self) {
+        let x_min = self.x_min_boundary() - 1;
+        self.insert_dense_column(x_min, true);
+        self.bounds.expand_bounds_horizontally();
+        self.apply_performance_tuning();
+    }
+
+    /// Adds an empty east column to the grid. The reason that this function isn't public is
+    /// because the grid always maintains the origin as its center with a bias toward the positive
+    /// coordinates and that assumption would break if this function were public.
+    /// This function should only be called when the number of columns is odd, in order to
+    /// maintain the centering around the origin.
+    fn add_east_column(&mut self) {
+        let x_max = self.x_max_boundary() + 1;
+        self.insert_dense_column(x_max, false);
+        self.bounds.expand_bounds_horizontally();
+        self.apply_performance_tuning();
+    }
+
+    /// Grow the grid to bounds that contain its current bounds. Every element keeps its
+    /// coordinate.
+    pub(super) fn grow_to(&mut self, bounds: OriginCenteredBounds) {
+        debug_assert!(bounds.is_within_bounds(&self.northwest_corner()));
+        debug_assert!(bounds.is_within_bounds(&self.southeast_corner()));
+        if self.storage.is_dense() {
+            self.storage.make_sparse(&self.bounds);
+        }
+        self.bounds = bounds;
+        self.apply_performance_tuning();
+    }
+
+    /// Inserts empty cells for a new column at `x` at the start or the end of every row if the
+    /// storage is dense. This must be called before the bounds are expanded.
+    fn insert_dense_column(&mut self, x: i32, at_row_start: bool) {
+        let x_count = OriginBounded::x_count(&self).as_u64() as usize;
+        let y_count = OriginBounded::y_count(&self).as_u64() as usize;
+        let rows = (self.y_min_boundary()..=self.y_max_boundary()).rev();
+
+        if let Some(cells) = self.storage.dense_cells_mut() {
+            let mut previous_cells = std::mem::take(cells).into_iter();
+            let mut new_cells = Vec::with_capacity(previous_cells.len() + y_count);
+
+            for y in rows {
+                if at_row_start {
+                    new_cells.push(GridCoordinate::Empty(Coordinate { x, y }));
+                }
+                new_cells.extend(previous_cells.by_ref().take(x_count));
+                if !at_row_start {
+                    new_cells.push(GridCoordinate::Empty(Coordinate { x, y }));
+                }
+            }
+
+            *cells = new_cells;
+        }
     }
 
     /// This method does two things:
@@ -557,31 +1161,105 @@ impl<T> Grid<T> {
         row: i32,
         direction: AbsoluteDirection,
     ) -> Result<(), GridError> {
-        if let Some(out_of_bounds_direction) =
-            self.out_of_bounds_directions(&Coordinate { x: 0, y: row })
-        {
-            let out_of_bounds_error = OutOfBoundsError::new(
-                Coordinate { x: 0, y: row },
-                out_of_bounds_direction.0,
-                out_of_bounds_direction.1,
-            );
+        self.filter_move_elements_in_direction(
+            |coordinate| filter(coordinate, row),
+            Coordinate { x: 0, y: row },
+            direction,
+        )
+    }
+
+    /// Same as [`Self::row_filter_move_elements_in_direction`], but the filter takes an i32 that
+    /// represents a column.
+    ///
+    /// # Errors
+    ///
+    /// The method returns an error in case of out of bounds or collision.
+    fn column_filter_move_elements_in_direction(
+        &mut self,
+        filter: fn(&Coordinate, i32) -> bool,
+        column: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        self.filter_move_elements_in_direction(
+            |coordinate| filter(coordinate, column),
+            Coordinate { x: column, y: 0 },
+            direction,
+        )
+    }
+
+    /// Moves all elements that pass the filter one step in the provided direction at once. `line`
+    /// is a coordinate on the row or column that the filter refers to, and it has to be within
+    /// bounds.
+    ///
+    /// On a toroidal grid, elements on the border wrap around to the opposite border, like with
+    /// [`move_element_in_direction`](Grid::move_element_in_direction()). An element may move to a
+    /// coordinate that another moving element leaves, so a full row or column rotates.
+    ///
+    /// Every move is checked before any element is moved, so the grid is unchanged if the method
+    /// returns an error.
+    ///
+    /// The performance tuning is not applied, since a move changes neither the bounds nor the
+    /// number of elements.
+    ///
+    /// # Errors
+    ///
+    /// The method returns an error in case of out of bounds or collision with an element that does
+    /// not move.
+    fn filter_move_elements_in_direction(
+        &mut self,
+        filter: impl Fn(&Coordinate) -> bool,
+        line: Coordinate,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        if let Some(out_of_bounds_direction) = self.out_of_bounds_directions(&line) {
+            let out_of_bounds_error =
+                OutOfBoundsError::new(line, out_of_bounds_direction.0, out_of_bounds_direction.1);
             return Err(GridError::OutOfBoundsError(out_of_bounds_error));
         }
 
-        let element_coordinates = self
+        let mut element_coordinates = self
             .iter_elements_new()
             .map(|(coordinate, _)| coordinate)
-            .filter(|c| filter(c, row))
+            .filter(|c| filter(c))
             .collect::<Vec<Coordinate>>();
-
+        // Elements at the front of the move come first, so that the first error is the same as
+        // when the elements are moved one by one.
         if direction == AbsoluteDirection::South || direction == AbsoluteDirection::East {
-            for c in element_coordinates.iter().rev() {
-                self.move_element_in_direction(c, direction)?;
-            }
-        } else if direction == AbsoluteDirection::North || direction == AbsoluteDirection::West {
-            for c in &element_coordinates {
-                self.move_element_in_direction(c, direction)?;
+            element_coordinates.reverse();
+        }
+        let moving = element_coordinates.iter().collect::<HashSet<_>>();
+
+        let mut targets = Vec::with_capacity(element_coordinates.len());
+        for coordinate in &element_coordinates {
+            let mut marker = BoundedMovingObject::try_from((&self, coordinate))?;
+            let target = if marker.move_in_absolute_direction_with(direction, 1, self.wrap_mode) {
+                *marker.position()
+            } else if self.wrap_mode == WrapMode::Toroidal {
+                *coordinate
+            } else {
+                return Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    marker.coordinate_in_direction(direction, 1),
+                    direction,
+                    None,
+                )));
+            };
+
+            if self.element_unchecked(&target).is_some() && !moving.contains(&target) {
+                return Err(GridError::CollisionError);
             }
+            targets.push(target);
+        }
+
+        let elements = element_coordinates
+            .iter()
+            .map(|coordinate| {
+                self.remove_element_without_tuning(coordinate)
+                    .expect("the moving coordinates are occupied")
+            })
+            .collect::<Vec<_>>();
+        for (target, element) in targets.iter().zip(elements) {
+            self.store_element_without_tuning(target, element)
+                .expect("the targets are within bounds");
         }
 
         Ok(())
@@ -605,31 +1283,236 @@ impl<T> Grid<T> {
     /// ```
     ///
     pub fn transpose_new(&mut self) {
+        self.transform_matrix_like(true, |[i, j], _| [j, i]);
+    }
+
+    /// Transpose the grid along the anti-diagonal, i.e. the diagonal from the northeast corner to
+    /// the southwest corner.
+    ///
+    /// Changes the size of an `NxM` grid to `MxN` and moves all elements from \[i\]\[j\]
+    /// to \[M-1-j\]\[N-1-i\] (defined as matrix-like coordinates rather than grid-like).
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Coordinate;
+    /// let mut grid = grid!(3, 2);
+    /// grid.store_element(&Coordinate{x: -1, y : 1}, () ); // northwest corner
+    /// grid.transpose_anti_diagonal();
+    /// assert!(grid.element(&Coordinate{x: 1, y : -1}).is_ok()); // southeast corner
+    /// ```
+    pub fn transpose_anti_diagonal(&mut self) {
+        self.transform_matrix_like(true, |[i, j], [i_max, j_max]| [j_max - j, i_max - i]);
+    }
+
+    /// Rotate the grid a quarter turn clockwise.
+    ///
+    /// Changes the size of an `NxM` grid to `MxN`. The northwest corner becomes the northeast
+    /// corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Coordinate;
+    /// let mut grid = grid!(3, 2);
+    /// grid.store_element(&Coordinate{x: -1, y : 1}, () ); // northwest corner
+    /// grid.rotate_clockwise();
+    /// assert!(grid.element(&Coordinate{x: 1, y : 1}).is_ok()); // northeast corner
+    /// ```
+    pub fn rotate_clockwise(&mut self) {
+        self.transform_matrix_like(true, |[i, j], [_, j_max]| [j_max - j, i]);
+    }
+
+    /// Rotate the grid a quarter turn counterclockwise.
+    ///
+    /// Changes the size of an `NxM` grid to `MxN`. The northwest corner becomes the southwest
+    /// corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Coordinate;
+    /// let mut grid = grid!(3, 2);
+    /// grid.store_element(&Coordinate{x: -1, y : 1}, () ); // northwest corner
+    /// grid.rotate_counterclockwise();
+    /// assert!(grid.element(&Coordinate{x: 0, y : -1}).is_ok()); // southwest corner
+    /// ```
+    pub fn rotate_counterclockwise(&mut self) {
+        self.transform_matrix_like(true, |[i, j], [i_max, _]| [j, i_max - i]);
+    }
+
+    /// Rotate the grid half a turn. The northwest corner becomes the southeast corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Coordinate;
+    /// let mut grid = grid!(2, 2);
+    /// grid.store_element(&Coordinate{x: 0, y : 1}, () ); // northwest corner
+    /// grid.rotate_180();
+    /// assert!(grid.element(&Coordinate{x: 1, y : 0}).is_ok()); // southeast corner
+    /// ```
+    pub fn rotate_180(&mut self) {
+        self.transform_matrix_like(false, |[i, j], [i_max, j_max]| [i_max - i, j_max - j]);
+    }
+
+    /// Mirror the grid from west to east. The northwest corner becomes the northeast corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Coordinate;
+    /// let mut grid = grid!(2, 2);
+    /// grid.store_element(&Coordinate{x: 0, y : 1}, () ); // northwest corner
+    /// grid.flip_horizontal();
+    /// assert!(grid.element(&Coordinate{x: 1, y : 1}).is_ok()); // northeast corner
+    /// ```
+    pub fn flip_horizontal(&mut self) {
+        self.transform_matrix_like(false, |[i, j], [i_max, _]| [i_max - i, j]);
+    }
+
+    /// Mirror the grid from north to south. The northwest corner becomes the southwest corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Coordinate;
+    /// let mut grid = grid!(2, 2);
+    /// grid.store_element(&Coordinate{x: 0, y : 1}, () ); // northwest corner
+    /// grid.flip_vertical();
+    /// assert!(grid.element(&Coordinate{x: 0, y : 0}).is_ok()); // southwest corner
+    /// ```
+    pub fn flip_vertical(&mut self) {
+        self.transform_matrix_like(false, |[i, j], [_, j_max]| [i, j_max - j]);
+    }
+
+    /// Move every element to new matrix-like coordinates.
+    ///
+    /// `transform` takes the matrix-like coordinates of an element along with the largest
+    /// matrix-like coordinates of the grid before the transform, and returns the matrix-like
+    /// coordinates of the element after the transform. If `swap_axes` is true, the x- and
+    /// y-counts of the grid are swapped.
+    fn transform_matrix_like(
+        &mut self,
+        swap_axes: bool,
+        transform: impl Fn([u32; 2], [u32; 2]) -> [u32; 2],
+    ) {
+        let (x_count, y_count) = if swap_axes {
+            (OriginBounded::y_count(&self), OriginBounded::x_count(&self))
+        } else {
+            (OriginBounded::x_count(&self), OriginBounded::y_count(&self))
+        };
         let old_grid = std::mem::replace(
             self,
-            Self::with_count(OriginBounded::y_count(&self), OriginBounded::x_count(&self)),
+            Self::with_count(x_count, y_count)
+                .with_performance_tuning(self.performance_tuning)
+                .with_wrap_mode(self.wrap_mode),
         );
 
-        let previous_bounds = Bounds::from_boundaries(
-            old_grid.x_min_boundary(),
-            old_grid.x_max_boundary(),
-            old_grid.y_min_boundary(),
-            old_grid.y_max_boundary(),
-        );
+        let previous_bounds = old_grid.bounds;
+        let largest = previous_bounds.to_matrix_like(&Coordinate {
+            x: previous_bounds.x_max_boundary(),
+            y: previous_bounds.y_min_boundary(),
+        });
 
-        for (coordinate, element) in old_grid {
+        for (coordinate, element) in old_grid.storage.into_elements(previous_bounds) {
             let matrix_coordinates = previous_bounds.to_matrix_like(&coordinate);
-            #[expect(clippy::missing_panics_doc)]
             let new_coordinate = self
-                .to_grid_like([matrix_coordinates[1], matrix_coordinates[0]])
-                .expect("Transposed coordinates cannot be out of bounds");
+                .to_grid_like(transform(matrix_coordinates, largest))
+                .expect("Transformed coordinates cannot be out of bounds");
+
+            self.store_element(&new_coordinate, element)
+                .expect("A transform shouldn't be able to collide or be out of bounds");
+        }
+    }
+
+    /// Trim all empty outer rows and columns and re-center the remaining grid on the origin.
+    ///
+    /// Returns the cropped grid along with the offset that was added to every coordinate, i.e. an
+    /// element at `c` in this grid is found at `c + offset` in the cropped grid. A grid without
+    /// elements is cropped to the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::grid;
+    /// use tudi::Bounded;
+    /// use tudi::Coordinate;
+    /// let mut grid: tudi::Grid<()> = grid!(5, 5);
+    /// grid.store_element(&Coordinate { x: 1, y: 1 }, ());
+    /// grid.store_element(&Coordinate { x: 2, y: 1 }, ());
+    ///
+    /// let (cropped, offset) = grid.crop_to_elements();
+    /// assert_eq!(cropped.x_count().as_u64(), 2);
+    /// assert_eq!(cropped.y_count().as_u64(), 1);
+    /// assert_eq!(offset, Coordinate { x: -1, y: -1 });
+    /// assert!(cropped.element(&(Coordinate { x: 1, y: 1 } + offset)).is_ok());
+    /// ```
+    pub fn crop_to_elements(self) -> (Self, Coordinate) {
+        let element_bounds = self
+            .iter_elements_new()
+            .map(|(coordinate, _)| coordinate)
+            .fold(None, |bounds: Option<[i32; 4]>, c| {
+                Some(match bounds {
+                    Some([x_min, x_max, y_min, y_max]) => [
+                        x_min.min(c.x),
+                        x_max.max(c.x),
+                        y_min.min(c.y),
+                        y_max.max(c.y),
+                    ],
+                    None => [c.x, c.x, c.y, c.y],
+                })
+            })
+            .map_or(Bounds::from_boundaries(0, 0, 0, 0), |[x1, x2, y1, y2]| {
+                Bounds::from_boundaries(x1, x2, y1, y2)
+            });
 
-            if let Some(e) = element {
-                #[expect(clippy::missing_panics_doc)]
-                self.store_element(&new_coordinate, e)
-                    .expect("Transpose shouldn't be able to collide or be out of bounds");
+        #[expect(clippy::missing_panics_doc)]
+        self.crop_with_offset(&element_bounds)
+            .expect("The bounds of the elements are within the bounds of the grid")
+    }
+
+    /// Extract a rectangular region as a new origin-centered grid. Elements outside of the region
+    /// are dropped.
+    ///
+    /// The region is shifted so that its corners line up with the corners of the new grid, i.e.
+    /// the northwest corner of the region becomes the northwest corner of the new grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the region is not within the bounds of the grid.
+    pub fn crop_to<B: Bounded>(self, region: &B) -> Result<Self, GridError> {
+        self.crop_with_offset(region).map(|(grid, _)| grid)
+    }
+
+    /// Extract a region as a new origin-centered grid, and return the offset that was added to
+    /// every coordinate.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the region is not within the bounds of the grid.
+    fn crop_with_offset<B: Bounded>(self, region: &B) -> Result<(Self, Coordinate), GridError> {
+        self.check_region(region)?;
+
+        let mut cropped = Self::with_count(region.x_count(), region.y_count())
+            .with_performance_tuning(self.performance_tuning)
+            .with_wrap_mode(self.wrap_mode);
+        let offset = Coordinate {
+            x: cropped.x_min_boundary(),
+            y: cropped.y_min_boundary(),
+        } - Coordinate {
+            x: region.x_min_boundary(),
+            y: region.y_min_boundary(),
+        };
+
+        for (coordinate, element) in self.storage.into_elements(self.bounds) {
+            if region.out_of_bounds_directions(&coordinate).is_none() {
+                cropped
+                    .store_element(&(coordinate + offset), element)
+                    .expect("Cropped coordinates cannot collide or be out of bounds");
             }
         }
+
+        Ok((cropped, offset))
     }
 
     #[deprecated(since = "0.3.0")]
@@ -643,55 +1526,204 @@ impl<T> Grid<T> {
         println!("-----");
     }
 
+    /// Checks that the grids have the same bounds and are occupied at the same coordinates,
+    /// without comparing the elements.
+    pub fn same_shape_and_occupancy<U>(&self, other: &Grid<U>) -> bool {
+        self.bounds == other.bounds
+            && self
+                .iter_elements_new()
+                .map(|(coordinate, _)| coordinate)
+                .eq(other.iter_elements_new().map(|(coordinate, _)| coordinate))
+    }
+
     /// A string where '#' marks a occupied element and '.' marks an empty element with one line for
     /// each row in the grid.
     /// A simple way to quickly see what is going on in a small grid.
     pub fn element_statuses(&self) -> String {
-        let mut result = String::with_capacity(
-            ((self.x_count().as_u64() + 1) * self.y_count().as_u64()) as usize,
-        );
-        for (index, element) in self.iter_new() {
-            if element.is_some() {
-                result.push('#');
-            } else {
-                result.push('.')
-            };
+        element_statuses(self, self.iter_new())
+    }
+}
 
-            if index.x_coordinate() == self.x_max_boundary()
-                && index.y_coordinate() != self.y_min_boundary()
-            {
-                result.push('\n');
-            }
+/// The smallest origin-centered count whose boundaries include `min` and `max`.
+///
+/// # Errors
+///
+/// This function returns an error if `min` is `i32::MIN`, since no origin-centered bounds contain
+/// it.
+pub(super) fn count_to_fit(min: i32, max: i32) -> Result<AxisCount, AxisCountError> {
+    let positive = u64::from(max.max(0).unsigned_abs());
+    let negative = u64::from(min.min(0).unsigned_abs());
+    let count = if positive > negative {
+        2 * positive
+    } else {
+        2 * negative + 1
+    };
+    AxisCount::try_from(count)
+}
+
+/// A string where '#' marks a occupied element and '.' marks an empty element with one line for
+/// each row in the bounds. The cells have to cover the bounds from west to east, north to south.
+pub(super) fn element_statuses<B: Bounded, U>(
+    bounds: &B,
+    cells: impl Iterator<Item = (Coordinate, Option<U>)>,
+) -> String {
+    let mut result = String::with_capacity(
+        ((bounds.x_count().as_u64() + 1) * bounds.y_count().as_u64()) as usize,
+    );
+    for (index, element) in cells {
+        if element.is_some() {
+            result.push('#');
+        } else {
+            result.push('.')
+        };
+
+        if index.x_coordinate() == bounds.x_max_boundary()
+            && index.y_coordinate() != bounds.y_min_boundary()
+        {
+            result.push('\n');
+        }
+    }
+    result
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if the coordinate is out of bounds or empty. See [`Grid::element`] for a fallible
+    /// alternative.
+    fn index(&self, coordinate: Coordinate) -> &T {
+        match self.element(&coordinate) {
+            Ok(element) => element,
+            Err(err) => panic!("cannot index grid at {coordinate}: {err}"),
+        }
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    /// # Panics
+    ///
+    /// Panics if the coordinate is out of bounds or empty. See [`Grid::get_mut_element`] for a
+    /// fallible alternative.
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut T {
+        match self.get_mut_element(&coordinate) {
+            Ok(element) => element,
+            Err(err) => panic!("cannot index grid at {coordinate}: {err}"),
+        }
+    }
+}
+
+impl<T> FromIterator<(Coordinate, T)> for Grid<T> {
+    /// Create the smallest grid that contains every coordinate of the iterator. If a coordinate
+    /// occurs more than once, the last element is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate has `i32::MIN` as its x- or y-coordinate, since no origin-centered
+    /// grid contains it.
+    fn from_iter<I: IntoIterator<Item = (Coordinate, T)>>(iter: I) -> Self {
+        let elements = iter.into_iter().collect::<Vec<(Coordinate, T)>>();
+
+        let x_count = count_to_fit(
+            elements.iter().map(|(c, _)| c.x).min().unwrap_or(0),
+            elements.iter().map(|(c, _)| c.x).max().unwrap_or(0),
+        )
+        .expect("no origin-centered grid contains i32::MIN");
+        let y_count = count_to_fit(
+            elements.iter().map(|(c, _)| c.y).min().unwrap_or(0),
+            elements.iter().map(|(c, _)| c.y).max().unwrap_or(0),
+        )
+        .expect("no origin-centered grid contains i32::MIN");
+
+        let mut grid = Self::with_count(x_count, y_count);
+        grid.try_extend(elements)
+            .expect("the grid is sized to fit every coordinate");
+        grid
+    }
+}
+
+impl<T> Extend<(Coordinate, T)> for Grid<T> {
+    /// # Panics
+    ///
+    /// Panics if a coordinate is out of bounds. See [`Grid::try_extend`] for a fallible
+    /// alternative.
+    fn extend<I: IntoIterator<Item = (Coordinate, T)>>(&mut self, iter: I) {
+        if let Err(err) = self.try_extend(iter) {
+            panic!("cannot extend grid: {err}");
+        }
+    }
+}
+
+impl<T> Default for Grid<T> {
+    /// An empty grid with a single coordinate at the origin.
+    fn default() -> Self {
+        Self::with_count(AxisCount::MIN, AxisCount::MIN)
+    }
+}
+
+impl<T> fmt::Debug for Grid<T> {
+    /// The bounds of the grid followed by its [element statuses](Grid::element_statuses).
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "Grid {}x{} ({:?})",
+            self.x_count().as_u64(),
+            self.y_count().as_u64(),
+            self.performance_tuning
+        )?;
+        formatter.write_str(&self.element_statuses())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = (Coordinate, Option<&'a T>);
+    type IntoIter = GridIter<'a, T>;
+
+    fn into_iter(self) -> GridIter<'a, T> {
+        self.iter_new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = (Coordinate, Option<&'a mut T>);
+    type IntoIter = GridIterMut<'a, T>;
+
+    fn into_iter(self) -> GridIterMut<'a, T> {
+        self.iter_mut_new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Grid<T> {
+    /// Two grids are equal if they have the same bounds, the same wrap mode and equal elements at
+    /// the same coordinates. The storage representation and performance tuning are not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.bounds == other.bounds
+            && self.wrap_mode == other.wrap_mode
+            && self.iter_elements_new().eq(other.iter_elements_new())
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x_count().as_u64().hash(state);
+        self.y_count().as_u64().hash(state);
+        self.wrap_mode.hash(state);
+        for (coordinate, element) in self.iter_elements_new() {
+            coordinate.hash(state);
+            element.hash(state);
         }
-        result
     }
 }
 
 impl<T> IntoIterator for Grid<T> {
     type Item = (Coordinate, Option<T>);
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-
-    fn into_iter(self) -> std::vec::IntoIter<Self::Item> {
-        let bounds = Bounds::from_boundaries(
-            self.x_min_boundary(),
-            self.x_max_boundary(),
-            self.y_min_boundary(),
-            self.y_max_boundary(),
-        );
+    type IntoIter = GridIntoIter<T>;
 
-        self.grid_data
-            .into_iter()
-            .enumerate()
-            .map(move |(index, grid_coordinate)| match grid_coordinate {
-                GridCoordinate::Object(val) => {
-                    (bounds.index_to_coordinate(index as u64).unwrap(), Some(val))
-                }
-                GridCoordinate::Empty(_) => {
-                    (bounds.index_to_coordinate(index as u64).unwrap(), None)
-                }
-            })
-            .collect::<Vec<(Coordinate, Option<T>)>>()
-            .into_iter()
+    fn into_iter(self) -> GridIntoIter<T> {
+        GridIntoIter::new(self)
     }
 }
 
@@ -749,25 +1781,21 @@ impl<T> TryFrom<Vec<Vec<Option<T>>>> for Grid<T> {
         let x_count = AxisCount::try_from(first_row_len)?;
         let y_count = AxisCount::try_from(value.len())?;
 
-        let mut grid_data: Vec<GridCoordinate<T>> = Vec::new();
         let mut result = Grid::with_count(x_count, y_count);
 
         for (y_count, line) in value.into_iter().enumerate() {
             for (x_count, element) in line.into_iter().enumerate() {
-                let coordinate = result
-                    .to_grid_like([x_count as u32, y_count as u32])
-                    .unwrap();
-
-                let grid_element = if let Some(val) = element {
-                    GridCoordinate::Object::<T>(val)
-                } else {
-                    GridCoordinate::Empty::<T>(coordinate)
-                };
-                grid_data.push(grid_element);
+                if let Some(val) = element {
+                    let coordinate = result
+                        .to_grid_like([x_count as u32, y_count as u32])
+                        .unwrap();
+                    result
+                        .store_element(&coordinate, val)
+                        .expect("coordinate is within bounds by construction");
+                }
             }
         }
 
-        result.grid_data = grid_data;
         Ok(result)
     }
 }
@@ -800,9 +1828,38 @@ pub mod tests {
     use std::collections::HashMap;
     use std::fs::read_to_string;
 
-    /// Checks that the boundaries of the grid are centered around the origin.
-    fn assert_centered_around_origin<T>(input: &Grid<T>) {
-        assert!(
+    /// Every performance tuning, for tests that must behave the same with each storage.
+    pub(crate) const TUNINGS: [PerformanceTuning; 3] = [
+        PerformanceTuning::Memory,
+        PerformanceTuning::Speed,
+        PerformanceTuning::Auto,
+    ];
+
+    /// A grid where the element at each coordinate with an even `x + y` is `10 * x + y`.
+    pub(crate) fn checkered_grid(
+        x_count: u64,
+        y_count: u64,
+        performance_tuning: PerformanceTuning,
+    ) -> Grid<i32> {
+        let mut grid = Grid::with_count(
+            AxisCount::from_u64_unchecked(x_count),
+            AxisCount::from_u64_unchecked(y_count),
+        )
+        .with_performance_tuning(performance_tuning);
+        for y in grid.y_min_boundary()..=grid.y_max_boundary() {
+            for x in grid.x_min_boundary()..=grid.x_max_boundary() {
+                if (x + y) % 2 == 0 {
+                    grid.store_element(&Coordinate { x, y }, 10 * x + y)
+                        .unwrap();
+                }
+            }
+        }
+        grid
+    }
+
+    /// Checks that the boundaries of the grid are centered around the origin.
+    fn assert_centered_around_origin<T>(input: &Grid<T>) {
+        assert!(
             -input.x_min_boundary() == input.x_max_boundary()
                 || input.x_max_boundary() == -input.x_min_boundary() + 1
         );
@@ -827,13 +1884,31 @@ pub mod tests {
         }
     }
 
-    /// Checks that the grid_data vec is consistent with the bounds in the struct. The bounds
-    /// imply a length and the grid_data should be that length.
+    /// Checks that the storage is consistent with the bounds in the struct. The bounds
+    /// imply a length that dense storage should have, and sparse storage should only contain
+    /// coordinates within the bounds.
     fn assert_grid_data_and_bounds_consistency<T>(input: &Grid<T>) {
-        let expected_count_by_bounds =
-            input.bounds.x_count().as_u64() * input.bounds.y_count().as_u64();
-        let actual_length = input.grid_data.len();
-        assert_eq!(expected_count_by_bounds, actual_length.try_into().unwrap());
+        match &input.storage {
+            GridStorage::Dense { cells, occupied } => {
+                let expected_count_by_bounds =
+                    input.bounds.x_count().as_u64() * input.bounds.y_count().as_u64();
+                assert_eq!(expected_count_by_bounds, cells.len().try_into().unwrap());
+                assert_eq!(
+                    occupied.load(std::sync::atomic::Ordering::Relaxed),
+                    cells
+                        .iter()
+                        .filter(|cell| matches!(cell, GridCoordinate::Object(_)))
+                        .count()
+                );
+            }
+            GridStorage::Sparse(elements) => {
+                assert!(
+                    elements
+                        .keys()
+                        .all(|key| input.is_within_bounds(&key.coordinate()))
+                );
+            }
+        }
     }
 
     /// # Panics
@@ -1410,11 +2485,11 @@ pub mod tests {
             );
             assert_eq!(
                 grid.coordinate_to_index(&grid.southwest_corner()).unwrap() as usize,
-                grid.grid_data.len() - n as usize
+                (n * n - n) as usize
             );
             assert_eq!(
                 grid.coordinate_to_index(&grid.southeast_corner()).unwrap() as usize,
-                grid.grid_data.len() - 1
+                (n * n - 1) as usize
             );
         }
     }
@@ -2006,4 +3081,1220 @@ pub mod tests {
             assert_eq!(actual_grid, expected_grid);
         }
     }
+
+    pub mod column_expansion {
+
+        use super::*;
+
+        #[test]
+        fn add_column_test() {
+            let mut grid: Grid<()> = empty_grid(3);
+            for _ in 1..10 {
+                grid.add_column();
+                assert_coordinate_coverage(&grid);
+                assert_centered_around_origin(&grid);
+                assert_grid_data_and_bounds_consistency(&grid);
+            }
+        }
+
+        #[test]
+        fn column_expansion_test() {
+            let mut grid: Grid<()> = empty_grid(3);
+            check_x_count(&grid, 3);
+            assert!(grid.add_column());
+            check_x_count(&grid, 4);
+            check_y_count(&grid, 3);
+            assert!(!grid.add_column());
+            check_x_count(&grid, 5);
+            assert_coordinate_coverage(&grid);
+            assert_centered_around_origin(&grid);
+        }
+
+        #[test]
+        fn add_column_keeps_elements() {
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            let expected = corners(&grid);
+            grid.add_column();
+            grid.add_column();
+            check_elements(&grid, expected, [&1; 4]);
+            check_element(&grid, Coordinate::default(), &1);
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[track_caller]
+        pub fn check_expand_at_column<T>(grid: &mut Grid<T>, x_coord: i32, right: bool) {
+            let actual = grid.expand_at_column(x_coord);
+            if right {
+                assert_eq!(actual, Ok(HorizontalDirection::East));
+            } else {
+                assert_eq!(actual, Ok(HorizontalDirection::West));
+            }
+        }
+
+        #[track_caller]
+        pub fn check_expand_at_column_err<T>(grid: &mut Grid<T>, x_coord: i32) {
+            let result = grid.expand_at_column(x_coord);
+            assert_eq!(
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: x_coord, y: 0 },
+                    if x_coord > grid.x_max_boundary() {
+                        AbsoluteDirection::East
+                    } else {
+                        AbsoluteDirection::West
+                    },
+                    None,
+                ))),
+                result
+            );
+        }
+
+        #[test]
+        fn basic_eastward_column_expansion_right_of_element() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element(3, c);
+
+            check_x_count(&grid, 3);
+            check_expand_at_column(&mut grid, 1, true);
+            check_x_count(&grid, 4);
+
+            // expansion happens eastwards right of the element so the object should not move.
+            check_element(&grid, c, &0);
+        }
+
+        #[test]
+        fn basic_eastward_column_expansion_left_of_element() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element(3, c);
+            check_expand_at_column(&mut grid, -1, true);
+            // expansion happens eastwards left of the element so the object should move.
+            check_empty(&grid, c);
+            check_element(&grid, Coordinate { x: 1, y: 0 }, &0);
+        }
+
+        #[test]
+        fn basic_eastward_column_expansion_on_element_column() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element(3, c);
+            check_expand_at_column(&mut grid, 0, true);
+
+            // expansion happens on the element column so the object should move eastwards.
+            check_empty(&grid, c);
+            check_element(&grid, Coordinate { x: 1, y: 0 }, &0);
+        }
+
+        #[test]
+        fn basic_westward_column_expansion_left_of_element() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element(4, c);
+            check_expand_at_column(&mut grid, -1, false);
+            check_element(&grid, c, &0);
+        }
+
+        #[test]
+        fn basic_westward_column_expansion_right_of_element() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element(4, c);
+            check_expand_at_column(&mut grid, 1, false);
+            check_empty(&grid, c);
+            check_element(&grid, Coordinate { x: -1, y: 0 }, &0);
+        }
+
+        #[test]
+        fn basic_westward_column_expansion_on_element_column() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element(4, c);
+            check_expand_at_column(&mut grid, 0, false);
+            check_empty(&grid, c);
+            check_element(&grid, Coordinate { x: -1, y: 0 }, &0);
+        }
+
+        #[test]
+        fn sparse_column_expansion() {
+            let c = Coordinate { x: 0, y: 0 };
+            let mut grid: Grid<usize> = grid_with_single_element::<usize>(3, c)
+                .with_performance_tuning(PerformanceTuning::Memory);
+            check_expand_at_column(&mut grid, 0, true);
+            check_expand_at_column(&mut grid, 0, false);
+            check_element(&grid, Coordinate { x: 1, y: 0 }, &0);
+            assert_centered_around_origin(&grid);
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[test]
+        fn out_of_bounds_should_err() {
+            check_expand_at_column_err(
+                &mut grid_with_single_element::<()>(4, Coordinate::default()),
+                -5,
+            );
+            check_expand_at_column_err(
+                &mut grid_with_single_element::<()>(1, Coordinate::default()),
+                2,
+            );
+            check_expand_at_column_err(
+                &mut grid_with_single_element::<()>(1, Coordinate::default()),
+                -1,
+            );
+        }
+    }
+
+    mod removal {
+        use super::*;
+
+        #[track_caller]
+        fn check_removal_is_consistent<T>(grid: &Grid<T>) {
+            assert_coordinate_coverage(grid);
+            assert_centered_around_origin(grid);
+            assert_grid_data_and_bounds_consistency(grid);
+        }
+
+        #[test]
+        fn remove_row_returns_elements() {
+            let mut grid: Grid<usize> = grid_with_occupied_at(
+                3,
+                [Coordinate { x: 1, y: 0 }, Coordinate { x: -1, y: 0 }],
+                [1, 2],
+            );
+            assert_eq!(
+                grid.remove_row(0),
+                Ok(vec![
+                    (Coordinate { x: -1, y: 0 }, 2),
+                    (Coordinate { x: 1, y: 0 }, 1)
+                ])
+            );
+            check_y_count(&grid, 2);
+            check_x_count(&grid, 3);
+            assert_eq!(grid.iter_elements_new().count(), 0);
+            check_removal_is_consistent(&grid);
+        }
+
+        #[test]
+        fn remove_row_reverses_expand_at_row() {
+            for count in [3, 4] {
+                for y in -1..=1 {
+                    let original: Grid<usize> = grid_with_occupied_corners_and_origin(count, 1);
+                    let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(count, 1);
+                    // the expansion always leaves the new empty row at y.
+                    grid.expand_at_row(y).unwrap();
+                    assert_eq!(grid.remove_row(y), Ok(vec![]));
+                    assert_eq!(grid, original);
+                    check_removal_is_consistent(&grid);
+                }
+            }
+        }
+
+        #[test]
+        fn remove_row_moves_elements() {
+            // even number of rows: elements above the row move down.
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(4, 1);
+            assert_eq!(grid.remove_row(0), Ok(vec![(Coordinate { x: 0, y: 0 }, 1)]));
+            check_y_count(&grid, 3);
+            check_elements(
+                &grid,
+                [
+                    Coordinate { x: -1, y: 1 },
+                    Coordinate { x: 2, y: 1 },
+                    Coordinate { x: -1, y: -1 },
+                    Coordinate { x: 2, y: -1 },
+                ],
+                [&1; 4],
+            );
+            check_removal_is_consistent(&grid);
+
+            // odd number of rows: elements below the row move up.
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            grid.remove_row(0).unwrap();
+            check_y_count(&grid, 2);
+            check_elements(
+                &grid,
+                [
+                    Coordinate { x: -1, y: 1 },
+                    Coordinate { x: 1, y: 1 },
+                    Coordinate { x: -1, y: 0 },
+                    Coordinate { x: 1, y: 0 },
+                ],
+                [&1; 4],
+            );
+            check_removal_is_consistent(&grid);
+        }
+
+        #[test]
+        fn remove_column_moves_elements() {
+            // even number of columns: elements right of the column move left.
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(4, 1);
+            grid.remove_column(0).unwrap();
+            check_x_count(&grid, 3);
+            check_elements(
+                &grid,
+                [
+                    Coordinate { x: -1, y: 2 },
+                    Coordinate { x: 1, y: 2 },
+                    Coordinate { x: -1, y: -1 },
+                    Coordinate { x: 1, y: -1 },
+                ],
+                [&1; 4],
+            );
+            check_removal_is_consistent(&grid);
+
+            // odd number of columns: elements left of the column move right.
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            assert_eq!(
+                grid.remove_column(0),
+                Ok(vec![(Coordinate { x: 0, y: 0 }, 1)])
+            );
+            check_x_count(&grid, 2);
+            check_elements(
+                &grid,
+                [
+                    Coordinate { x: 0, y: 1 },
+                    Coordinate { x: 1, y: 1 },
+                    Coordinate { x: 0, y: -1 },
+                    Coordinate { x: 1, y: -1 },
+                ],
+                [&1; 4],
+            );
+            check_removal_is_consistent(&grid);
+        }
+
+        #[test]
+        fn remove_column_reverses_expand_at_column() {
+            for count in [3, 4] {
+                for x in -1..=1 {
+                    let original: Grid<usize> = grid_with_occupied_corners_and_origin(count, 1);
+                    let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(count, 1);
+                    // the expansion always leaves the new empty column at x.
+                    grid.expand_at_column(x).unwrap();
+                    assert_eq!(grid.remove_column(x), Ok(vec![]));
+                    assert_eq!(grid, original);
+                    check_removal_is_consistent(&grid);
+                }
+            }
+        }
+
+        #[test]
+        fn sparse_removal() {
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(5, 1)
+                .with_performance_tuning(PerformanceTuning::Memory);
+            grid.remove_row(2).unwrap();
+            grid.remove_column(-2).unwrap();
+            check_x_count(&grid, 4);
+            check_y_count(&grid, 4);
+            check_elements(
+                &grid,
+                [Coordinate { x: 0, y: 1 }, Coordinate { x: 2, y: -1 }],
+                [&1; 2],
+            );
+            assert_eq!(grid.iter_elements_new().count(), 2);
+            check_removal_is_consistent(&grid);
+        }
+
+        #[test]
+        fn removing_last_row_or_column_should_err() {
+            let mut grid: Grid<usize> = grid_with_single_element(1, Coordinate::default());
+            assert_eq!(grid.remove_row(0), Err(GridError::MinimumSizeError));
+            assert_eq!(grid.remove_column(0), Err(GridError::MinimumSizeError));
+            check_element(&grid, Coordinate::default(), &0);
+        }
+
+        #[test]
+        fn out_of_bounds_should_err() {
+            let mut grid: Grid<usize> = grid_with_single_element(3, Coordinate::default());
+            assert_eq!(
+                grid.remove_row(2),
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: 0, y: 2 },
+                    AbsoluteDirection::North,
+                    None
+                )))
+            );
+            assert_eq!(
+                grid.remove_column(-2),
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: -2, y: 0 },
+                    AbsoluteDirection::West,
+                    None
+                )))
+            );
+        }
+    }
+
+    mod collection_traits {
+        use super::*;
+
+        #[test]
+        fn index() {
+            let mut grid: Grid<usize> = grid_with_occupied_at(3, [Coordinate::default()], [1]);
+            assert_eq!(grid[Coordinate::default()], 1);
+            grid[Coordinate::default()] = 2;
+            check_element(&grid, Coordinate::default(), &2);
+        }
+
+        #[test]
+        #[should_panic]
+        fn index_empty_coordinate_should_panic() {
+            let grid: Grid<usize> = empty_grid(3);
+            let _ = grid[Coordinate::default()];
+        }
+
+        #[test]
+        #[should_panic]
+        fn index_out_of_bounds_should_panic() {
+            let mut grid: Grid<usize> = empty_grid(3);
+            grid[Coordinate { x: 2, y: 0 }] = 1;
+        }
+
+        #[test]
+        fn from_iter_sizes_grid_to_fit() {
+            let grid: Grid<usize> = [
+                (Coordinate { x: -2, y: 0 }, 1),
+                (Coordinate { x: 1, y: 3 }, 2),
+            ]
+            .into_iter()
+            .collect();
+            check_x_count(&grid, 5);
+            check_y_count(&grid, 6);
+            assert_centered_around_origin(&grid);
+            check_element(&grid, Coordinate { x: -2, y: 0 }, &1);
+            check_element(&grid, Coordinate { x: 1, y: 3 }, &2);
+        }
+
+        #[test]
+        fn from_iter_keeps_last_duplicate() {
+            let grid: Grid<usize> = [(Coordinate::default(), 1), (Coordinate::default(), 2)]
+                .into_iter()
+                .collect();
+            check_x_count(&grid, 1);
+            check_y_count(&grid, 1);
+            check_element(&grid, Coordinate::default(), &2);
+        }
+
+        #[test]
+        fn from_empty_iter() {
+            let grid: Grid<usize> = std::iter::empty().collect();
+            assert_eq!(grid, Grid::default());
+        }
+
+        #[test]
+        fn from_iter_fits_even_counts() {
+            for (min, max, count) in [(0, 1, 2), (-1, 2, 4), (-2, 2, 5), (-3, 2, 7), (0, 3, 6)] {
+                let grid: Grid<()> = [
+                    (Coordinate { x: min, y: max }, ()),
+                    (Coordinate { x: max, y: min }, ()),
+                ]
+                .into_iter()
+                .collect();
+                check_x_count(&grid, count);
+                check_y_count(&grid, count);
+            }
+        }
+
+        #[test]
+        fn extend() {
+            let mut grid: Grid<usize> = empty_grid(3);
+            grid.extend([(Coordinate::default(), 1), (Coordinate { x: 1, y: 1 }, 2)]);
+            check_element(&grid, Coordinate::default(), &1);
+            check_element(&grid, Coordinate { x: 1, y: 1 }, &2);
+        }
+
+        #[test]
+        fn try_extend_stops_at_out_of_bounds() {
+            let mut grid: Grid<usize> = empty_grid(3);
+            let result = grid.try_extend([
+                (Coordinate::default(), 1),
+                (Coordinate { x: 0, y: 2 }, 2),
+                (Coordinate { x: 1, y: 1 }, 3),
+            ]);
+            assert_eq!(
+                result,
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: 0, y: 2 },
+                    AbsoluteDirection::North,
+                    None
+                )))
+            );
+            check_element(&grid, Coordinate::default(), &1);
+            check_empty(&grid, Coordinate { x: 1, y: 1 });
+        }
+
+        #[test]
+        #[should_panic]
+        fn extend_out_of_bounds_should_panic() {
+            let mut grid: Grid<usize> = empty_grid(3);
+            grid.extend([(Coordinate { x: 0, y: 2 }, 1)]);
+        }
+
+        #[test]
+        fn into_iter_by_reference() {
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            for (_, element) in &mut grid {
+                if let Some(element) = element {
+                    *element += 1;
+                }
+            }
+
+            let mut count = 0;
+            for (coordinate, element) in &grid {
+                assert_eq!(element, grid.element_unchecked(&coordinate));
+                count += 1;
+            }
+            assert_eq!(count, 9);
+            check_elements(&grid, corners(&grid), [&2; 4]);
+        }
+
+        #[test]
+        fn iter_mut_new_matches_iter_new() {
+            for performance_tuning in TUNINGS {
+                let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(4, 1)
+                    .with_performance_tuning(performance_tuning);
+                let expected = grid
+                    .iter_new()
+                    .map(|(coordinate, element)| (coordinate, element.copied()))
+                    .collect::<Vec<_>>();
+                let actual = grid
+                    .iter_mut_new()
+                    .map(|(coordinate, element)| (coordinate, element.copied()))
+                    .collect::<Vec<_>>();
+                assert_eq!(actual, expected);
+            }
+        }
+
+        #[test]
+        fn default_is_single_empty_coordinate() {
+            let grid: Grid<()> = Grid::default();
+            check_x_count(&grid, 1);
+            check_y_count(&grid, 1);
+            assert!(grid.element(&Coordinate::default()).is_err());
+        }
+
+        #[test]
+        fn debug_renders_element_statuses() {
+            let grid: Grid<()> = grid_with_occupied_at(2, [Coordinate::default()], [()]);
+            assert_eq!(format!("{grid:?}"), "Grid 2x2 (Auto)\n..\n#.");
+        }
+    }
+
+    mod equality {
+        use super::*;
+        use std::hash::DefaultHasher;
+
+        fn hash_of<T: Hash>(grid: &Grid<T>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            grid.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        #[test]
+        fn equality_compares_values() {
+            let first: Grid<usize> = grid_with_occupied_at(3, [Coordinate::default()], [1]);
+            let second: Grid<usize> = grid_with_occupied_at(3, [Coordinate::default()], [2]);
+            assert_ne!(first, second);
+            assert!(first.same_shape_and_occupancy(&second));
+            assert_eq!(
+                first,
+                grid_with_occupied_at(3, [Coordinate::default()], [1])
+            );
+        }
+
+        #[test]
+        fn equality_compares_bounds_and_occupancy() {
+            let first: Grid<usize> = grid_with_occupied_at(3, [Coordinate::default()], [1]);
+            let moved: Grid<usize> = grid_with_occupied_at(3, [Coordinate { x: 1, y: 0 }], [1]);
+            let larger: Grid<usize> = grid_with_occupied_at(4, [Coordinate::default()], [1]);
+            assert_ne!(first, moved);
+            assert_ne!(first, larger);
+            assert!(!first.same_shape_and_occupancy(&moved));
+            assert!(!first.same_shape_and_occupancy(&larger));
+        }
+
+        #[test]
+        fn same_shape_and_occupancy_ignores_element_type() {
+            let first: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            let second: Grid<char> = grid_with_occupied_corners_and_origin(3, 'a');
+            assert!(first.same_shape_and_occupancy(&second));
+        }
+
+        #[test]
+        fn equality_and_hash_ignore_performance_tuning() {
+            let memory = grid_with_occupied_corners_and_origin(5, 1)
+                .with_performance_tuning(PerformanceTuning::Memory);
+            let speed = grid_with_occupied_corners_and_origin(5, 1)
+                .with_performance_tuning(PerformanceTuning::Speed);
+            assert_eq!(memory, speed);
+            assert_eq!(hash_of(&memory), hash_of(&speed));
+        }
+
+        #[test]
+        fn equality_and_hash_compare_wrap_mode() {
+            let bounded: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            let toroidal = bounded.clone().with_wrap_mode(WrapMode::Toroidal);
+            assert_ne!(bounded, toroidal);
+            assert_ne!(hash_of(&bounded), hash_of(&toroidal));
+            assert!(bounded.same_shape_and_occupancy(&toroidal));
+            assert_eq!(toroidal.with_wrap_mode(WrapMode::Bounded), bounded);
+        }
+
+        #[test]
+        fn hash_distinguishes_values() {
+            let first: Grid<usize> = grid_with_occupied_at(3, [Coordinate::default()], [1]);
+            let second: Grid<usize> = grid_with_occupied_at(3, [Coordinate::default()], [2]);
+            assert_ne!(hash_of(&first), hash_of(&second));
+        }
+
+        #[test]
+        fn clone_is_independent() {
+            let grid: Grid<usize> = grid_with_occupied_corners_and_origin(4, 1)
+                .with_performance_tuning(PerformanceTuning::Speed);
+            let mut clone = grid.clone();
+            assert_eq!(grid, clone);
+            assert_eq!(clone.performance_tuning(), PerformanceTuning::Speed);
+
+            clone.remove_element(&Coordinate::default()).unwrap();
+            assert_ne!(grid, clone);
+            check_element(&grid, Coordinate::default(), &1);
+            assert_grid_data_and_bounds_consistency(&clone);
+        }
+    }
+
+    mod transforms {
+        use super::*;
+
+        /// A 3x2 grid with distinct elements.
+        const WIDE: &str = "ab.\n..c";
+
+        fn grid_from(input: &str) -> Grid<char> {
+            let map: HashMap<char, char> = ('a'..='z').map(|c| (c, c)).collect();
+            Grid::from_str_by_map(input, &map).unwrap()
+        }
+
+        fn render(grid: &Grid<char>) -> String {
+            let mut result = String::new();
+            for (coordinate, element) in grid.iter_new() {
+                result.push(element.copied().unwrap_or('.'));
+                if coordinate.x == grid.x_max_boundary() && coordinate.y != grid.y_min_boundary() {
+                    result.push('\n');
+                }
+            }
+            result
+        }
+
+        #[track_caller]
+        fn check_transform(input: &str, transform: fn(&mut Grid<char>), expected: &str) {
+            for performance_tuning in [PerformanceTuning::Memory, PerformanceTuning::Speed] {
+                let mut grid = grid_from(input).with_performance_tuning(performance_tuning);
+                transform(&mut grid);
+                assert_eq!(render(&grid), expected);
+                assert_centered_around_origin(&grid);
+                assert_grid_data_and_bounds_consistency(&grid);
+            }
+        }
+
+        #[test]
+        fn rotate_clockwise() {
+            check_transform(WIDE, Grid::rotate_clockwise, ".a\n.b\nc.");
+        }
+
+        #[test]
+        fn rotate_counterclockwise() {
+            check_transform(WIDE, Grid::rotate_counterclockwise, ".c\nb.\na.");
+        }
+
+        #[test]
+        fn rotate_180() {
+            check_transform(WIDE, Grid::rotate_180, "c..\n.ba");
+        }
+
+        #[test]
+        fn flip_horizontal() {
+            check_transform(WIDE, Grid::flip_horizontal, ".ba\nc..");
+        }
+
+        #[test]
+        fn flip_vertical() {
+            check_transform(WIDE, Grid::flip_vertical, "..c\nab.");
+        }
+
+        #[test]
+        fn transpose() {
+            check_transform(WIDE, Grid::transpose_new, "a.\nb.\n.c");
+        }
+
+        #[test]
+        fn transpose_anti_diagonal() {
+            check_transform(WIDE, Grid::transpose_anti_diagonal, "c.\n.b\n.a");
+        }
+
+        #[test]
+        fn four_rotations_are_identity() {
+            for input in [WIDE, "ab\ncd", "a..\n.b.\n..c", "a...\n..b.\nc..d"] {
+                let mut grid = grid_from(input);
+                for _ in 0..4 {
+                    grid.rotate_clockwise();
+                }
+                assert_eq!(render(&grid), input);
+
+                grid.rotate_counterclockwise();
+                grid.rotate_clockwise();
+                assert_eq!(render(&grid), input);
+            }
+        }
+
+        #[test]
+        fn transforms_compose() {
+            for input in [WIDE, "ab\ncd", "a...\n..b.\nc..d"] {
+                let mut flipped = grid_from(input);
+                flipped.flip_horizontal();
+                flipped.flip_vertical();
+                let mut rotated = grid_from(input);
+                rotated.rotate_180();
+                assert_eq!(render(&flipped), render(&rotated));
+
+                let mut anti_transposed = grid_from(input);
+                anti_transposed.transpose_anti_diagonal();
+                let mut rotated = grid_from(input);
+                rotated.rotate_clockwise();
+                rotated.flip_vertical();
+                assert_eq!(render(&anti_transposed), render(&rotated));
+            }
+        }
+
+        #[test]
+        fn even_counts_keep_the_positive_bias() {
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(4, 1);
+            grid.rotate_clockwise();
+            check_elements(&grid, corners(&grid), [&1; 4]);
+            // the origin of an even grid is not its center, so it moves.
+            check_element(&grid, Coordinate { x: 0, y: 1 }, &1);
+            check_empty(&grid, Coordinate::default());
+            assert_eq!(grid.iter_elements_new().count(), 5);
+        }
+    }
+
+    mod crop {
+        use super::*;
+
+        #[test]
+        fn crop_to_elements_trims_empty_lines() {
+            let grid: Grid<usize> = grid_with_occupied_at(
+                7,
+                [Coordinate { x: -2, y: 3 }, Coordinate { x: 1, y: 0 }],
+                [1, 2],
+            );
+            let (cropped, offset) = grid.crop_to_elements();
+            check_x_count(&cropped, 4);
+            check_y_count(&cropped, 4);
+            assert_eq!(offset, Coordinate { x: 1, y: -1 });
+            check_element(&cropped, Coordinate { x: -1, y: 2 }, &1);
+            check_element(&cropped, Coordinate { x: 2, y: -1 }, &2);
+            assert_eq!(cropped.empty_rows(), vec![0, 1]);
+            assert_eq!(cropped.empty_columns(), vec![0, 1]);
+            assert_centered_around_origin(&cropped);
+            assert_grid_data_and_bounds_consistency(&cropped);
+        }
+
+        #[test]
+        fn crop_to_elements_of_full_grid_is_noop() {
+            let grid: Grid<usize> = grid_with_occupied_corners_and_origin(4, 1);
+            let statuses = grid.element_statuses();
+            let (cropped, offset) = grid.crop_to_elements();
+            assert_eq!(offset, Coordinate::default());
+            assert_eq!(cropped.element_statuses(), statuses);
+        }
+
+        #[test]
+        fn crop_empty_grid_to_elements() {
+            let (cropped, offset) = empty_grid::<usize>(5).crop_to_elements();
+            check_x_count(&cropped, 1);
+            check_y_count(&cropped, 1);
+            assert_eq!(offset, Coordinate::default());
+        }
+
+        #[test]
+        fn crop_to_region() {
+            let grid: Grid<usize> = grid_with_occupied_corners_and_origin(5, 1)
+                .with_performance_tuning(PerformanceTuning::Memory);
+            let cropped = grid.crop_to(&Bounds::from_boundaries(0, 2, -2, 0)).unwrap();
+            check_x_count(&cropped, 3);
+            check_y_count(&cropped, 3);
+            assert_eq!(cropped.element_statuses(), "#..\n...\n..#");
+            assert_grid_data_and_bounds_consistency(&cropped);
+        }
+
+        #[test]
+        fn crop_to_out_of_bounds_region_should_err() {
+            let grid: Grid<usize> = grid_with_occupied_corners_and_origin(3, 1);
+            assert_eq!(
+                grid.crop_to(&Bounds::from_boundaries(-1, 2, -1, 1)),
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: 2, y: -1 },
+                    AbsoluteDirection::East,
+                    None
+                )))
+            );
+        }
+    }
+
+    mod move_elements_of_column_in_direction {
+        use super::*;
+
+        #[test]
+        fn out_of_bounds_should_err() {
+            let mut grid: Grid<usize> = grid_with_occupied_corners_and_origin(2, 1);
+            assert_eq!(
+                grid.move_elements_left_of_column_in_direction(-2, AbsoluteDirection::West),
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: -2, y: 0 },
+                    AbsoluteDirection::West,
+                    None
+                )))
+            );
+            assert_eq!(
+                grid.move_elements_right_of_column_in_direction(2, AbsoluteDirection::East),
+                Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                    Coordinate { x: 2, y: 0 },
+                    AbsoluteDirection::East,
+                    None
+                )))
+            );
+        }
+
+        #[test]
+        fn valid_move() {
+            let mut actual_grid: Grid<usize> =
+                grid_with_occupied_at(3, [Coordinate { x: 0, y: 0 }], [1]);
+            let _ =
+                actual_grid.move_elements_right_of_column_in_direction(0, AbsoluteDirection::East);
+            let expected_grid: Grid<usize> =
+                grid_with_occupied_at(3, [Coordinate { x: 1, y: 0 }], [1]);
+            assert_eq!(actual_grid, expected_grid);
+
+            let _ =
+                actual_grid.move_elements_left_of_column_in_direction(1, AbsoluteDirection::West);
+            let _ =
+                actual_grid.move_elements_left_of_column_in_direction(0, AbsoluteDirection::West);
+            let expected_grid: Grid<usize> =
+                grid_with_occupied_at(3, [Coordinate { x: -1, y: 0 }], [1]);
+            assert_eq!(actual_grid, expected_grid);
+        }
+
+        #[test]
+        fn collision_should_err() {
+            let mut grid: Grid<usize> = grid_with_occupied_at(
+                3,
+                [Coordinate { x: 0, y: 0 }, Coordinate { x: 1, y: 0 }],
+                [1, 2],
+            );
+            assert_eq!(
+                grid.move_elements_left_of_column_in_direction(0, AbsoluteDirection::East),
+                Err(GridError::CollisionError)
+            );
+        }
+    }
+
+    mod performance_tuning {
+        use super::*;
+
+        fn tuned_grid_with_occupied_corners_and_origin(
+            count: u64,
+            performance_tuning: PerformanceTuning,
+        ) -> Grid<usize> {
+            grid_with_occupied_corners_and_origin(count, 1)
+                .with_performance_tuning(performance_tuning)
+        }
+
+        #[track_caller]
+        fn check_same_elements(first: &Grid<usize>, second: &Grid<usize>) {
+            assert_eq!(first.element_statuses(), second.element_statuses());
+            assert!(first.iter_elements_new().eq(second.iter_elements_new()));
+        }
+
+        #[test]
+        fn memory_tuning_is_sparse() {
+            let grid = tuned_grid_with_occupied_corners_and_origin(3, PerformanceTuning::Memory);
+            assert!(!grid.storage.is_dense());
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[test]
+        fn speed_tuning_is_dense() {
+            let grid = tuned_grid_with_occupied_corners_and_origin(100, PerformanceTuning::Speed);
+            assert!(grid.storage.is_dense());
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[test]
+        fn switching_tuning_keeps_elements() {
+            let speed = tuned_grid_with_occupied_corners_and_origin(5, PerformanceTuning::Speed);
+            let memory = tuned_grid_with_occupied_corners_and_origin(5, PerformanceTuning::Memory);
+            check_same_elements(&speed, &memory);
+            check_elements(&memory, corners(&memory), [&1; 4]);
+
+            let speed_again = memory.with_performance_tuning(PerformanceTuning::Speed);
+            check_same_elements(&speed, &speed_again);
+        }
+
+        #[test]
+        fn sparse_grid_behaves_like_dense_grid() {
+            let mut speed =
+                tuned_grid_with_occupied_corners_and_origin(6, PerformanceTuning::Speed);
+            let mut memory =
+                tuned_grid_with_occupied_corners_and_origin(6, PerformanceTuning::Memory);
+
+            for grid in [&mut speed, &mut memory] {
+                grid.move_element_in_direction(&Coordinate::default(), AbsoluteDirection::East)
+                    .unwrap();
+                grid.expand_at_row(0).unwrap();
+                grid.add_row();
+                grid.transpose_new();
+                for (_, element) in grid.iter_mut_elements_new() {
+                    *element += 1;
+                }
+                assert_grid_data_and_bounds_consistency(grid);
+                assert_coordinate_coverage(grid);
+            }
+
+            check_same_elements(&speed, &memory);
+            assert!(speed.into_iter().eq(memory.into_iter()));
+        }
+
+        #[test]
+        fn set_performance_tuning() {
+            let mut grid: Grid<()> = empty_grid(3);
+            assert_eq!(grid.performance_tuning(), PerformanceTuning::Auto);
+            grid.set_performance_tuning(PerformanceTuning::Speed);
+            assert_eq!(grid.performance_tuning(), PerformanceTuning::Speed);
+            assert!(grid.speed_cache.is_some());
+            grid.set_performance_tuning(PerformanceTuning::Memory);
+            assert!(grid.speed_cache.is_none());
+        }
+
+        #[test]
+        fn speed_cache_stays_in_sync() {
+            let mut grids = TUNINGS.map(|tuning| {
+                grid_with_occupied_at(
+                    7,
+                    [Coordinate { x: -3, y: 3 }, Coordinate { x: 1, y: -2 }],
+                    [1, 2],
+                )
+                .with_performance_tuning(tuning)
+            });
+
+            for grid in &mut grids {
+                check_store(grid, Coordinate { x: 2, y: 2 }, 3, StoreValidity::Valid);
+                grid.remove_element(&Coordinate { x: -3, y: 3 }).unwrap();
+                grid.expand_at_row(1).unwrap();
+                grid.add_row();
+                grid.transpose_new();
+                check_store(grid, Coordinate { x: -3, y: -3 }, 4, StoreValidity::Valid);
+            }
+
+            let [memory, speed, auto] = &grids;
+            assert!(speed.speed_cache.is_some());
+            assert_eq!(speed.empty_rows(), memory.empty_rows());
+            assert_eq!(speed.empty_rows(), auto.empty_rows());
+            assert_eq!(speed.empty_columns(), memory.empty_columns());
+            assert_eq!(speed.empty_columns(), auto.empty_columns());
+            check_same_elements(speed, memory);
+            check_same_elements(speed, auto);
+        }
+
+        #[test]
+        fn speed_tuning_iter_mut_new() {
+            let mut grid = tuned_grid_with_occupied_corners_and_origin(4, PerformanceTuning::Speed);
+            for (_, element) in grid.iter_mut_elements_new() {
+                *element = 5;
+            }
+            let coordinates = grid
+                .iter_mut_new()
+                .map(|(coordinate, _)| coordinate)
+                .collect::<Vec<Coordinate>>();
+            assert!(
+                coordinates
+                    .into_iter()
+                    .eq(grid.iter_new().map(|(coordinate, _)| coordinate))
+            );
+            check_elements(&grid, corners(&grid), [&5; 4]);
+        }
+
+        #[test]
+        fn auto_tuning_switches_representation_by_fill_ratio() {
+            let mut grid: Grid<()> = empty_grid(100);
+            assert!(!grid.storage.is_dense());
+
+            let coordinates = grid
+                .iter_new()
+                .map(|(coordinate, _)| coordinate)
+                .take(1251)
+                .collect::<Vec<Coordinate>>();
+
+            for coordinate in &coordinates {
+                check_store(&mut grid, *coordinate, (), StoreValidity::Valid);
+            }
+            assert!(grid.storage.is_dense());
+            assert_grid_data_and_bounds_consistency(&grid);
+
+            for coordinate in &coordinates[..1000] {
+                grid.remove_element(coordinate).unwrap();
+            }
+            assert!(!grid.storage.is_dense());
+            assert_grid_data_and_bounds_consistency(&grid);
+            assert_eq!(grid.iter_elements_new().count(), 251);
+        }
+
+        #[test]
+        fn moves_keep_the_representation_at_the_threshold() {
+            let mut grid: Grid<()> =
+                empty_grid(100).with_performance_tuning(PerformanceTuning::Speed);
+            // 313 of 10_000 coordinates is just above the 1/32 fill that keeps dense storage.
+            let coordinates = grid
+                .iter_new()
+                .map(|(coordinate, _)| coordinate)
+                .filter(|coordinate| coordinate.x < -10)
+                .take(313)
+                .collect::<Vec<Coordinate>>();
+            for coordinate in &coordinates {
+                check_store(&mut grid, *coordinate, (), StoreValidity::Valid);
+            }
+            grid.set_performance_tuning(PerformanceTuning::Auto);
+            assert!(grid.storage.is_dense());
+
+            grid.move_elements_left_of_column_in_direction(0, AbsoluteDirection::East)
+                .unwrap();
+            assert!(grid.storage.is_dense());
+            assert_eq!(grid.iter_elements_new().count(), 313);
+            assert_grid_data_and_bounds_consistency(&grid);
+
+            let (coordinate, _) = grid.iter_elements_new().next().unwrap();
+            grid.move_element_in_direction(&coordinate, AbsoluteDirection::West)
+                .unwrap();
+            assert!(grid.storage.is_dense());
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[test]
+        fn large_sparse_grid() {
+            let count = AxisCount::try_from(100_000).unwrap();
+            let mut grid: Grid<usize> =
+                Grid::with_count(count, count).with_performance_tuning(PerformanceTuning::Memory);
+            let coordinate = Coordinate {
+                x: 49_000,
+                y: -49_000,
+            };
+            check_store(&mut grid, coordinate, 7, StoreValidity::Valid);
+            check_element(&grid, coordinate, &7);
+            grid.add_row();
+            check_y_count(&grid, 100_001);
+            check_element(&grid, coordinate, &7);
+            assert_eq!(grid.iter_elements_new().count(), 1);
+        }
+    }
+
+    mod wrap_mode {
+        use super::*;
+        use crate::grid;
+
+        fn torus() -> Grid<char> {
+            let mut grid = grid!(5, 3).with_wrap_mode(WrapMode::Toroidal);
+            grid.store_element(&Coordinate { x: 2, y: 0 }, 'a').unwrap();
+            grid.store_element(&Coordinate { x: -2, y: 1 }, 'b')
+                .unwrap();
+            grid
+        }
+
+        #[test]
+        fn element_access_wraps() {
+            let mut grid = torus();
+            check_element(&grid, Coordinate { x: -3, y: 0 }, &'a');
+            check_element(&grid, Coordinate { x: 3, y: -2 }, &'b');
+            assert_eq!(
+                grid.element_unchecked(&Coordinate { x: 7, y: 3 }),
+                Some(&'a')
+            );
+
+            *grid.get_mut_element(&Coordinate { x: -8, y: 0 }).unwrap() = 'c';
+            assert_eq!(
+                grid.store_element(&Coordinate { x: 0, y: 4 }, 'd'),
+                Ok(None)
+            );
+            assert_eq!(grid.remove_element(&Coordinate { x: 3, y: 1 }), Ok('b'));
+            assert_eq!(grid.element_statuses(), "..#..\n....#\n.....");
+            check_element(&grid, Coordinate { x: 2, y: 0 }, &'c');
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[test]
+        fn bounded_grids_do_not_wrap() {
+            let mut grid = torus();
+            grid.set_wrap_mode(WrapMode::Bounded);
+            assert!(grid.element(&Coordinate { x: -3, y: 0 }).is_err());
+            check_store(
+                &mut grid,
+                Coordinate { x: 3, y: 0 },
+                'c',
+                StoreValidity::OutOfBounds,
+            );
+            assert!(
+                grid.move_element_in_direction(&Coordinate { x: 2, y: 0 }, AbsoluteDirection::East)
+                    .is_err()
+            );
+        }
+
+        #[test]
+        fn moves_wrap_around_the_borders() {
+            let mut grid = torus();
+            assert_eq!(
+                grid.move_element_in_direction(&Coordinate { x: 2, y: 0 }, AbsoluteDirection::East),
+                Ok(Coordinate { x: -2, y: 0 })
+            );
+            assert_eq!(
+                grid.move_element_in_direction(
+                    &Coordinate { x: -2, y: 0 },
+                    AbsoluteDirection::North
+                ),
+                Err(GridError::CollisionError)
+            );
+            assert_eq!(
+                grid.move_element_in_direction(
+                    &Coordinate { x: -2, y: 1 },
+                    AbsoluteDirection::North
+                ),
+                Ok(Coordinate { x: -2, y: -1 })
+            );
+            check_element(&grid, Coordinate { x: -2, y: -1 }, &'b');
+            assert_grid_data_and_bounds_consistency(&grid);
+        }
+
+        #[test]
+        fn moves_on_a_single_row_wrap_onto_themselves() {
+            let mut grid = grid!(3, 1).with_wrap_mode(WrapMode::Toroidal);
+            grid.store_element(&Coordinate::default(), ()).unwrap();
+            assert_eq!(
+                grid.move_element_in_direction(&Coordinate::default(), AbsoluteDirection::North),
+                Ok(Coordinate::default())
+            );
+            assert_eq!(
+                grid.move_element_in_direction(
+                    &Coordinate { x: 1, y: 0 },
+                    AbsoluteDirection::South
+                ),
+                Err(GridError::UnoccupiedError(Coordinate { x: 1, y: 0 }))
+            );
+        }
+
+        #[test]
+        fn bulk_moves_wrap_around_the_borders() {
+            let mut grid = torus();
+            assert_eq!(
+                grid.move_elements_right_of_column_in_direction(2, AbsoluteDirection::East),
+                Ok(())
+            );
+            check_element(&grid, Coordinate { x: -2, y: 0 }, &'a');
+            assert_eq!(
+                grid.move_elements_above_row_in_direction(1, AbsoluteDirection::North),
+                Ok(())
+            );
+            check_element(&grid, Coordinate { x: -2, y: -1 }, &'b');
+            assert_eq!(grid.element_statuses(), ".....\n#....\n#....");
+        }
+
+        #[test]
+        fn bulk_moves_rotate_full_rows() {
+            let mut grid = grid!(3, 1).with_wrap_mode(WrapMode::Toroidal);
+            for (x, element) in [(-1, 'a'), (0, 'b'), (1, 'c')] {
+                grid.store_element(&Coordinate { x, y: 0 }, element)
+                    .unwrap();
+            }
+            assert_eq!(
+                grid.move_elements_left_of_column_in_direction(1, AbsoluteDirection::East),
+                Ok(())
+            );
+            assert_eq!(
+                grid.iter_elements_new()
+                    .map(|(_, element)| *element)
+                    .collect::<String>(),
+                "cab"
+            );
+        }
+
+        #[test]
+        fn failed_bulk_moves_leave_the_grid_unchanged() {
+            for wrap_mode in [WrapMode::Bounded, WrapMode::Toroidal] {
+                let mut grid = grid!(5, 3).with_wrap_mode(wrap_mode);
+                grid.store_element(&Coordinate { x: -2, y: -1 }, 'a')
+                    .unwrap();
+                grid.store_element(&Coordinate { x: 0, y: 1 }, 'b').unwrap();
+                grid.store_element(&Coordinate { x: 1, y: 1 }, 'c').unwrap();
+                let expected = grid.clone();
+                assert_eq!(
+                    grid.move_elements_left_of_column_in_direction(0, AbsoluteDirection::East),
+                    Err(GridError::CollisionError)
+                );
+                assert_eq!(grid, expected);
+            }
+
+            let mut grid = torus();
+            grid.store_element(&Coordinate { x: -2, y: 0 }, 'c')
+                .unwrap();
+            grid.store_element(&Coordinate { x: 1, y: -1 }, 'd')
+                .unwrap();
+            let expected = grid.clone();
+            assert_eq!(
+                grid.move_elements_right_of_column_in_direction(0, AbsoluteDirection::East),
+                Err(GridError::CollisionError)
+            );
+            assert_eq!(grid, expected);
+        }
+
+        #[test]
+        fn neighbors_wrap() {
+            let grid = torus();
+            let corner = grid.southwest_corner();
+            let mut neighbors = grid.neighbors(&corner, Connectivity::Euclid);
+            neighbors.sort_by_key(|coordinate| (coordinate.x, coordinate.y));
+            assert_eq!(neighbors.len(), 8);
+            assert!(
+                neighbors
+                    .iter()
+                    .all(|neighbor| grid.is_within_bounds(neighbor))
+            );
+            assert!(neighbors.contains(&grid.northeast_corner()));
+
+            let thin: Grid<()> = grid!(2, 1).with_wrap_mode(WrapMode::Toroidal);
+            assert_eq!(
+                thin.neighbors(&Coordinate::default(), Connectivity::Manhattan),
+                [
+                    Coordinate::default(),
+                    Coordinate { x: 1, y: 0 },
+                    Coordinate::default(),
+                    Coordinate { x: 1, y: 0 }
+                ]
+            );
+        }
+
+        #[test]
+        fn derived_grids_keep_the_wrap_mode() {
+            let mut grid = torus();
+            grid.rotate_clockwise();
+            assert_eq!(grid.wrap_mode(), WrapMode::Toroidal);
+            let grid = grid
+                .crop_to(&Bounds::from_boundaries(-1, 1, -1, 1))
+                .unwrap();
+            assert_eq!(grid.wrap_mode(), WrapMode::Toroidal);
+            assert_eq!(grid.map(|_| 0).wrap_mode(), WrapMode::Toroidal);
+        }
+
+        #[test]
+        fn flood_fill_wraps() {
+            let mut grid = grid!(5, 5).with_wrap_mode(WrapMode::Toroidal);
+            for y in -2..=2 {
+                grid.store_element(&Coordinate { x: 0, y }, ()).unwrap();
+            }
+            let filled = grid
+                .flood_fill(
+                    Coordinate { x: 2, y: 0 },
+                    Connectivity::Manhattan,
+                    |_, wall| wall.is_none(),
+                )
+                .unwrap();
+            assert_eq!(filled.len(), 20);
+        }
+    }
 }
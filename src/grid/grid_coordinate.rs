@@ -6,6 +6,29 @@ pub enum GridCoordinate<T> {
     Object(T),
 }
 
+impl<T> GridCoordinate<T> {
+    pub fn object(&self) -> Option<&T> {
+        match self {
+            GridCoordinate::Object(object) => Some(object),
+            GridCoordinate::Empty(_) => None,
+        }
+    }
+
+    pub fn into_object(self) -> Option<T> {
+        match self {
+            GridCoordinate::Object(object) => Some(object),
+            GridCoordinate::Empty(_) => None,
+        }
+    }
+
+    pub fn object_mut(&mut self) -> Option<&mut T> {
+        match self {
+            GridCoordinate::Object(object) => Some(object),
+            GridCoordinate::Empty(_) => None,
+        }
+    }
+}
+
 impl<T: Positioned + Clone> Positioned for GridCoordinate<T> {
     fn position(&self) -> &Coordinate {
         match self {
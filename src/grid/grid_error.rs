@@ -15,4 +15,10 @@ pub enum GridError {
 
     #[error("Unoccupied at {0:?}")]
     UnoccupiedError(Coordinate),
+
+    #[error("A grid must have at least one row and one column")]
+    MinimumSizeError,
+
+    #[error("The grids have different bounds")]
+    ShapeMismatchError,
 }
@@ -1,57 +1,296 @@
 use super::Grid;
-use crate::AbsoluteDirection;
-use crate::BoundedMovingObject;
+use super::grid_storage::CellsMut;
+use super::grid_storage::CellsRef;
+use super::grid_storage::IntoCells;
+use super::grid_storage::cell_count;
+use super::speed_cache::SpeedCache;
 use crate::Coordinate;
-use crate::Positioned;
+use crate::OriginCenteredBounds;
 use crate::bounded::Bounded;
+use std::iter::FusedIterator;
 
+/// The coordinates that a grid iterator has yet to visit, from west to east, north to south.
+#[derive(Debug, Clone)]
+struct Indices<'a> {
+    bounds: OriginCenteredBounds,
+    /// The coordinate at each index, if the grid caches them.
+    coordinates: Option<&'a [Coordinate]>,
+    /// The index of the next coordinate from the front.
+    front: u64,
+    /// One past the index of the next coordinate from the back.
+    back: u64,
+}
+
+impl<'a> Indices<'a> {
+    fn new(bounds: OriginCenteredBounds, speed_cache: Option<&'a SpeedCache>) -> Self {
+        Self {
+            bounds,
+            coordinates: speed_cache.map(SpeedCache::coordinates),
+            front: 0,
+            back: cell_count(&bounds),
+        }
+    }
+
+    fn coordinate(&self, index: u64) -> Coordinate {
+        match self.coordinates {
+            Some(coordinates) => coordinates[index as usize],
+            None => self
+                .bounds
+                .index_to_coordinate(index)
+                .expect("every index below the cell count is within bounds"),
+        }
+    }
+
+    fn next(&mut self) -> Option<Coordinate> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.coordinate(self.front - 1)
+        })
+    }
+
+    fn next_back(&mut self) -> Option<Coordinate> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.coordinate(self.back)
+        })
+    }
+
+    fn len(&self) -> usize {
+        usize::try_from(self.back - self.front).expect("the coordinate count fits in a usize")
+    }
+}
+
+/// Implements the iterator traits for a grid iterator with `indices` and `cells` fields.
+macro_rules! impl_grid_iterator {
+    ($iter:ty, $element:ty $(, $lifetime:lifetime)?) => {
+        impl<$($lifetime,)? T> Iterator for $iter {
+            type Item = (Coordinate, Option<$element>);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let coordinate = self.indices.next()?;
+                Some((coordinate, self.cells.next(coordinate)))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.indices.len(), Some(self.indices.len()))
+            }
+        }
+
+        impl<$($lifetime,)? T> DoubleEndedIterator for $iter {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let coordinate = self.indices.next_back()?;
+                Some((coordinate, self.cells.next_back(coordinate)))
+            }
+        }
+
+        impl<$($lifetime,)? T> ExactSizeIterator for $iter {}
+
+        impl<$($lifetime,)? T> FusedIterator for $iter {}
+    };
+}
+
+/// An iterator over all coordinates of a [`Grid`] along with their elements, from west to east,
+/// north to south.
+///
+/// This struct is created by [`Grid::iter_new`].
 #[derive(Debug)]
 pub struct GridIter<'a, T> {
-    current: BoundedMovingObject,
-    grid: &'a Grid<T>,
-    visited_last: bool,
+    indices: Indices<'a>,
+    cells: CellsRef<'a, T>,
 }
 
 impl<'a, T> GridIter<'a, T> {
     pub fn new(grid: &'a Grid<T>) -> Self {
-        let mut current = BoundedMovingObject::from_bounded(&grid.bounds());
-        current.set_current_x_to_x_min();
-        current.set_current_y_to_y_max();
         Self {
-            current,
-            grid,
-            visited_last: false,
+            indices: Indices::new(grid.bounds, grid.speed_cache.as_ref()),
+            cells: grid.storage.cells(),
+        }
+    }
+}
+
+impl_grid_iterator!(GridIter<'a, T>, &'a T, 'a);
+
+/// An iterator over all coordinates of a [`Grid`] along with mutable references to their elements,
+/// from west to east, north to south.
+///
+/// This struct is created by [`Grid::iter_mut_new`].
+#[derive(Debug)]
+pub struct GridIterMut<'a, T> {
+    indices: Indices<'a>,
+    cells: CellsMut<'a, T>,
+}
+
+impl<'a, T> GridIterMut<'a, T> {
+    pub(super) fn new(grid: &'a mut Grid<T>) -> Self {
+        Self {
+            indices: Indices::new(grid.bounds, grid.speed_cache.as_ref()),
+            cells: grid.storage.cells_mut(),
         }
     }
 }
 
-impl<'a, T> Iterator for GridIter<'a, T> {
-    type Item = (Coordinate, Option<&'a T>);
-    fn next(&mut self) -> Option<Self::Item> {
-        if &self.current.southeast_corner() == self.current.position() {
-            if self.visited_last {
-                return None;
-            } else {
-                self.visited_last = true;
+impl_grid_iterator!(GridIterMut<'a, T>, &'a mut T, 'a);
+
+/// An iterator that moves all coordinates of a [`Grid`] along with their elements out of the grid,
+/// from west to east, north to south.
+///
+/// This struct is created by the [`IntoIterator`] implementation of [`Grid`].
+#[derive(Debug)]
+pub struct GridIntoIter<T> {
+    indices: Indices<'static>,
+    cells: IntoCells<T>,
+}
+
+impl<T> GridIntoIter<T> {
+    pub(super) fn new(grid: Grid<T>) -> Self {
+        Self {
+            indices: Indices::new(grid.bounds, None),
+            cells: grid.storage.into_cells(),
+        }
+    }
+}
+
+impl_grid_iterator!(GridIntoIter<T>, T);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+    use crate::PerformanceTuning;
+    use crate::grid::generic_grid::tests::TUNINGS;
+
+    fn grid_with_elements(
+        x_count: u64,
+        y_count: u64,
+        elements: &[Coordinate],
+        performance_tuning: PerformanceTuning,
+    ) -> Grid<usize> {
+        let mut grid = Grid::with_count(
+            AxisCount::from_u64_unchecked(x_count),
+            AxisCount::from_u64_unchecked(y_count),
+        )
+        .with_performance_tuning(performance_tuning);
+        for (index, coordinate) in elements.iter().enumerate() {
+            grid.store_element(coordinate, index).unwrap();
+        }
+        grid
+    }
+
+    fn expected(grid: &Grid<usize>) -> Vec<(Coordinate, Option<usize>)> {
+        let mut result = Vec::new();
+        for y in (grid.y_min_boundary()..=grid.y_max_boundary()).rev() {
+            for x in grid.x_min_boundary()..=grid.x_max_boundary() {
+                let coordinate = Coordinate { x, y };
+                result.push((coordinate, grid.element_unchecked(&coordinate).copied()));
             }
         }
+        result
+    }
+
+    fn owned<'a>(
+        iter: impl Iterator<Item = (Coordinate, Option<&'a usize>)>,
+    ) -> Vec<(Coordinate, Option<usize>)> {
+        iter.map(|(coordinate, element)| (coordinate, element.copied()))
+            .collect()
+    }
 
-        let result = (
-            *self.current.position(),
-            self.grid.element_unchecked(self.current.position()),
-        );
+    #[test]
+    fn iterators_visit_every_coordinate_in_order() {
+        let elements = [
+            Coordinate { x: -1, y: 1 },
+            Coordinate { x: 0, y: 0 },
+            Coordinate { x: 2, y: -1 },
+        ];
+        for performance_tuning in TUNINGS {
+            let mut grid = grid_with_elements(4, 3, &elements, performance_tuning);
+            let expected = expected(&grid);
 
-        if self
-            .current
-            .move_in_absolute_direction(AbsoluteDirection::East, 1)
-        {
-        } else if self
-            .current
-            .move_in_absolute_direction(AbsoluteDirection::South, 1)
-        {
-            self.current.set_current_x_to_x_min();
-        };
+            assert_eq!(owned(grid.iter_new()), expected);
+            assert_eq!(
+                grid.iter_mut_new()
+                    .map(|(coordinate, element)| (coordinate, element.copied()))
+                    .collect::<Vec<_>>(),
+                expected
+            );
+            assert_eq!(grid.into_iter().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn iterators_are_double_ended() {
+        let elements = [Coordinate { x: -1, y: 1 }, Coordinate { x: 2, y: -1 }];
+        for performance_tuning in TUNINGS {
+            let mut grid = grid_with_elements(4, 3, &elements, performance_tuning);
+            let mut expected = expected(&grid);
+            expected.reverse();
+
+            assert_eq!(owned(grid.iter_new().rev()), expected);
+            assert_eq!(
+                grid.iter_mut_new()
+                    .rev()
+                    .map(|(coordinate, element)| (coordinate, element.copied()))
+                    .collect::<Vec<_>>(),
+                expected
+            );
+            assert_eq!(grid.into_iter().rev().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn iterating_from_both_ends_meets_in_the_middle() {
+        // Depending on the order, an element may be taken from either end before it is visited.
+        for x in -2..=2 {
+            for back_first in [false, true] {
+                for performance_tuning in TUNINGS {
+                    let element = Coordinate { x, y: 0 };
+                    let grid = grid_with_elements(5, 1, &[element], performance_tuning);
+                    let mut iter = grid.iter_new();
+                    let mut visited = Vec::new();
+                    for step in 0..5 {
+                        let next = if (step % 2 == 0) != back_first {
+                            iter.next()
+                        } else {
+                            iter.next_back()
+                        };
+                        visited.push(next.unwrap());
+                    }
+                    assert!(iter.next().is_none());
+                    assert_eq!(
+                        visited
+                            .iter()
+                            .filter_map(|(coordinate, element)| element.map(|_| *coordinate))
+                            .collect::<Vec<_>>(),
+                        vec![element]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iterators_have_exact_size() {
+        for performance_tuning in TUNINGS {
+            let grid = grid_with_elements(5, 4, &[Coordinate::default()], performance_tuning);
+            let mut iter = grid.iter_new();
+            assert_eq!(iter.len(), 20);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.len(), 18);
+            assert_eq!(iter.count(), 18);
+
+            let mut into_iter = grid.into_iter();
+            into_iter.nth(10);
+            assert_eq!(into_iter.len(), 9);
+        }
+    }
 
-        Some(result)
+    #[test]
+    fn iterators_are_fused() {
+        let mut grid = grid_with_elements(1, 1, &[Coordinate::default()], PerformanceTuning::Auto);
+        let mut iter = grid.iter_mut_new();
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
     }
 }
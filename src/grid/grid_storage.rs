@@ -0,0 +1,701 @@
+use super::grid_coordinate::GridCoordinate;
+use crate::Bounds;
+use crate::Coordinate;
+use crate::bounded::Bounded;
+use itertools::Either;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::btree_map;
+use std::iter::Map;
+use std::slice;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering as AtomicOrdering;
+
+/// The elements of a [`Grid`](super::Grid).
+///
+/// Dense storage keeps one entry per coordinate, ordered from west to east, north to south (see
+/// [`Bounded::coordinate_to_index`]). Sparse storage only keeps the occupied coordinates, ordered
+/// the same way, so that both representations iterate their elements in the same order.
+///
+/// The storage does not know the bounds of the grid. Methods that need to translate between
+/// indices and coordinates take both, or the bounds, as arguments.
+#[derive(Debug)]
+pub(super) enum GridStorage<T> {
+    Dense {
+        cells: Vec<GridCoordinate<T>>,
+        /// The number of occupied cells. It is atomic so that the splits of a
+        /// [`GridViewMut`](super::GridViewMut) can keep it up to date from several threads.
+        occupied: AtomicUsize,
+    },
+    Sparse(BTreeMap<RowMajorKey, T>),
+}
+
+impl<T: Clone> Clone for GridStorage<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Dense { cells, occupied } => Self::Dense {
+                cells: cells.clone(),
+                occupied: AtomicUsize::new(occupied.load(AtomicOrdering::Relaxed)),
+            },
+            Self::Sparse(elements) => Self::Sparse(elements.clone()),
+        }
+    }
+}
+
+/// A coordinate that is ordered from west to east, north to south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct RowMajorKey(Coordinate);
+
+impl RowMajorKey {
+    pub(super) fn new(coordinate: Coordinate) -> Self {
+        Self(coordinate)
+    }
+
+    pub(super) fn coordinate(self) -> Coordinate {
+        self.0
+    }
+}
+
+impl Ord for RowMajorKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.y.cmp(&self.0.y).then(self.0.x.cmp(&other.0.x))
+    }
+}
+
+impl PartialOrd for RowMajorKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> GridStorage<T> {
+    /// Dense storage with an empty entry for every coordinate in the bounds.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the number of coordinates does not fit in a usize.
+    pub(super) fn dense<B: Bounded>(bounds: &B) -> Self {
+        let cell_count = cell_count(bounds);
+        let cell_count =
+            usize::try_from(cell_count).expect("cell count is greater than usize::MAX");
+
+        let mut cells = Vec::with_capacity(cell_count);
+        for y in (bounds.y_min_boundary()..=bounds.y_max_boundary()).rev() {
+            for x in bounds.x_min_boundary()..=bounds.x_max_boundary() {
+                cells.push(GridCoordinate::Empty(Coordinate { x, y }));
+            }
+        }
+
+        Self::Dense {
+            cells,
+            occupied: AtomicUsize::new(0),
+        }
+    }
+
+    pub(super) fn sparse() -> Self {
+        Self::Sparse(BTreeMap::new())
+    }
+
+    pub(super) fn is_dense(&self) -> bool {
+        matches!(self, Self::Dense { .. })
+    }
+
+    /// The number of occupied coordinates.
+    pub(super) fn len(&self) -> usize {
+        match self {
+            Self::Dense { occupied, .. } => occupied.load(AtomicOrdering::Relaxed),
+            Self::Sparse(elements) => elements.len(),
+        }
+    }
+
+    /// The dense row-major cells, if the storage is dense.
+    pub(super) fn dense_cells_mut(&mut self) -> Option<&mut Vec<GridCoordinate<T>>> {
+        match self {
+            Self::Dense { cells, .. } => Some(cells),
+            Self::Sparse(_) => None,
+        }
+    }
+
+    /// The dense row-major cells and the number of occupied cells, if the storage is dense.
+    pub(super) fn dense_parts_mut(&mut self) -> Option<(&mut [GridCoordinate<T>], &AtomicUsize)> {
+        match self {
+            Self::Dense { cells, occupied } => Some((cells, occupied)),
+            Self::Sparse(_) => None,
+        }
+    }
+
+    pub(super) fn get(&self, index: u64, coordinate: Coordinate) -> Option<&T> {
+        match self {
+            Self::Dense { cells, .. } => match &cells[dense_index(index)] {
+                GridCoordinate::Object(element) => Some(element),
+                GridCoordinate::Empty(_) => None,
+            },
+            Self::Sparse(elements) => elements.get(&RowMajorKey(coordinate)),
+        }
+    }
+
+    pub(super) fn get_mut(&mut self, index: u64, coordinate: Coordinate) -> Option<&mut T> {
+        match self {
+            Self::Dense { cells, .. } => match &mut cells[dense_index(index)] {
+                GridCoordinate::Object(element) => Some(element),
+                GridCoordinate::Empty(_) => None,
+            },
+            Self::Sparse(elements) => elements.get_mut(&RowMajorKey(coordinate)),
+        }
+    }
+
+    /// Store an element and return the previous element at the coordinate, if any.
+    pub(super) fn insert(&mut self, index: u64, coordinate: Coordinate, element: T) -> Option<T> {
+        match self {
+            Self::Dense { cells, occupied } => {
+                match std::mem::replace(
+                    &mut cells[dense_index(index)],
+                    GridCoordinate::Object(element),
+                ) {
+                    GridCoordinate::Object(previous) => Some(previous),
+                    GridCoordinate::Empty(_) => {
+                        *occupied.get_mut() += 1;
+                        None
+                    }
+                }
+            }
+            Self::Sparse(elements) => elements.insert(RowMajorKey(coordinate), element),
+        }
+    }
+
+    /// Remove and return the element at the coordinate, if any.
+    pub(super) fn remove(&mut self, index: u64, coordinate: Coordinate) -> Option<T> {
+        match self {
+            Self::Dense { cells, occupied } => {
+                match std::mem::replace(
+                    &mut cells[dense_index(index)],
+                    GridCoordinate::Empty(coordinate),
+                ) {
+                    GridCoordinate::Object(previous) => {
+                        *occupied.get_mut() -= 1;
+                        Some(previous)
+                    }
+                    GridCoordinate::Empty(_) => None,
+                }
+            }
+            Self::Sparse(elements) => elements.remove(&RowMajorKey(coordinate)),
+        }
+    }
+
+    /// The occupied coordinates and their elements, from west to east, north to south.
+    pub(super) fn elements<'a, B: Bounded>(
+        &'a self,
+        bounds: &'a B,
+    ) -> impl Iterator<Item = (Coordinate, &'a T)> + 'a {
+        match self {
+            Self::Dense { cells, .. } => {
+                Either::Left(cells.iter().enumerate().filter_map(move |(index, cell)| {
+                    match cell {
+                        GridCoordinate::Object(element) => Some((
+                            bounds
+                                .index_to_coordinate(index as u64)
+                                .expect("every dense index is within bounds"),
+                            element,
+                        )),
+                        GridCoordinate::Empty(_) => None,
+                    }
+                }))
+            }
+            Self::Sparse(elements) => Either::Right(
+                elements
+                    .iter()
+                    .map(|(key, element)| (key.coordinate(), element)),
+            ),
+        }
+    }
+
+    /// The element at each coordinate, from west to east, north to south.
+    pub(super) fn cells(&self) -> CellsRef<'_, T> {
+        match self {
+            Self::Dense { cells, .. } => {
+                Cells::Dense(cells.iter().map(GridCoordinate::object as fn(_) -> _))
+            }
+            Self::Sparse(elements) => {
+                Cells::sparse(elements.iter().map(with_coordinate as fn(_) -> _))
+            }
+        }
+    }
+
+    /// The element at each coordinate of a region, from west to east, north to south. The region
+    /// has to be within the bounds.
+    pub(super) fn region<'a, B: Bounded + Copy + 'a>(
+        &'a self,
+        bounds: B,
+        region: Bounds,
+    ) -> impl Iterator<Item = (Coordinate, Option<&'a T>)> + 'a {
+        let (x_min, x_max) = (region.x_min_boundary(), region.x_max_boundary());
+        let rows = (region.y_min_boundary()..=region.y_max_boundary()).rev();
+        match self {
+            Self::Dense { cells, .. } => Either::Left(rows.flat_map(move |y| {
+                let start = dense_index(
+                    bounds
+                        .coordinate_to_index(&Coordinate { x: x_min, y })
+                        .expect("the region is within bounds"),
+                );
+                let row = &cells[start..=start + x_min.abs_diff(x_max) as usize];
+                (x_min..=x_max)
+                    .zip(row)
+                    .map(move |(x, cell)| (Coordinate { x, y }, cell.object()))
+            })),
+            Self::Sparse(elements) => Either::Right(rows.flat_map(move |y| {
+                let mut row = elements
+                    .range(
+                        RowMajorKey(Coordinate { x: x_min, y })..=RowMajorKey(Coordinate {
+                            x: x_max,
+                            y,
+                        }),
+                    )
+                    .peekable();
+                (x_min..=x_max).map(move |x| {
+                    let coordinate = Coordinate { x, y };
+                    let element = row
+                        .next_if(|(key, _)| key.coordinate() == coordinate)
+                        .map(|(_, element)| element);
+                    (coordinate, element)
+                })
+            })),
+        }
+    }
+
+    /// Mutable access to the element at each coordinate of a region, from west to east, north to
+    /// south. The region has to be within the bounds.
+    pub(super) fn region_mut<'a, B: Bounded>(
+        &'a mut self,
+        bounds: &B,
+        region: Bounds,
+    ) -> impl Iterator<Item = (Coordinate, Option<&'a mut T>)> + use<'a, B, T> {
+        let (x_min, x_max) = (region.x_min_boundary(), region.x_max_boundary());
+        let (y_min, y_max) = (region.y_min_boundary(), region.y_max_boundary());
+        let coordinates = (y_min..=y_max)
+            .rev()
+            .flat_map(move |y| (x_min..=x_max).map(move |x| Coordinate { x, y }));
+
+        match self {
+            Self::Dense { cells, .. } => {
+                let x_count = bounds.x_count().as_u64() as usize;
+                let first_row = bounds.y_max_boundary().abs_diff(y_max) as usize;
+                let first_column = x_min.abs_diff(bounds.x_min_boundary()) as usize;
+                let width = x_min.abs_diff(x_max) as usize + 1;
+                Either::Left(
+                    coordinates.zip(
+                        cells
+                            .chunks_mut(x_count)
+                            .skip(first_row)
+                            .take(y_min.abs_diff(y_max) as usize + 1)
+                            .flat_map(move |row| &mut row[first_column..first_column + width])
+                            .map(GridCoordinate::object_mut),
+                    ),
+                )
+            }
+            Self::Sparse(elements) => {
+                let mut elements =
+                    elements
+                        .range_mut(
+                            RowMajorKey(Coordinate { x: x_min, y: y_max })
+                                ..=RowMajorKey(Coordinate { x: x_max, y: y_min }),
+                        )
+                        .filter(move |(key, _)| (x_min..=x_max).contains(&key.coordinate().x))
+                        .peekable();
+                Either::Right(coordinates.map(move |coordinate| {
+                    let element = elements
+                        .next_if(|(key, _)| key.coordinate() == coordinate)
+                        .map(|(_, element)| element);
+                    (coordinate, element)
+                }))
+            }
+        }
+    }
+
+    /// Mutable access to the element at each coordinate, one row at a time from north to south.
+    ///
+    /// Dense storage splits its cells into rows without allocating. Sparse storage collects the
+    /// occupied coordinates of each row when the row is reached.
+    pub(super) fn rows_mut<B: Bounded>(
+        &mut self,
+        bounds: &B,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Coordinate, Option<&mut T>)>> {
+        let (x_min, x_max) = (bounds.x_min_boundary(), bounds.x_max_boundary());
+        let rows = (bounds.y_min_boundary()..=bounds.y_max_boundary()).rev();
+
+        match self {
+            Self::Dense { cells, .. } => {
+                let x_count = bounds.x_count().as_u64() as usize;
+                Either::Left(cells.chunks_mut(x_count).zip(rows).map(move |(row, y)| {
+                    Either::Left(
+                        (x_min..=x_max)
+                            .zip(row)
+                            .map(move |(x, cell)| (Coordinate { x, y }, cell.object_mut())),
+                    )
+                }))
+            }
+            Self::Sparse(elements) => {
+                let mut elements = elements.iter_mut().peekable();
+                Either::Right(rows.map(move |y| {
+                    let mut row = Vec::new();
+                    while let Some((key, element)) =
+                        elements.next_if(|(key, _)| key.coordinate().y == y)
+                    {
+                        row.push((key.coordinate(), element));
+                    }
+                    Either::Right(fill_line(
+                        (x_min..=x_max).map(move |x| Coordinate { x, y }),
+                        row,
+                    ))
+                }))
+            }
+        }
+    }
+
+    /// Mutable access to the element at each coordinate, one column at a time from west to east.
+    ///
+    /// Dense storage only keeps an iterator over each row and collects one column at a time.
+    /// Sparse storage orders its occupied coordinates by column first.
+    pub(super) fn columns_mut<B: Bounded>(
+        &mut self,
+        bounds: &B,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Coordinate, Option<&mut T>)>> {
+        let (y_min, y_max) = (bounds.y_min_boundary(), bounds.y_max_boundary());
+        let columns = bounds.x_min_boundary()..=bounds.x_max_boundary();
+
+        match self {
+            Self::Dense { cells, .. } => {
+                let x_count = bounds.x_count().as_u64() as usize;
+                let mut rows = cells
+                    .chunks_mut(x_count)
+                    .map(<[_]>::iter_mut)
+                    .collect::<Vec<_>>();
+                Either::Left(columns.map(move |x| {
+                    let column = rows
+                        .iter_mut()
+                        .map(|row| {
+                            row.next()
+                                .expect("every row has a cell in each column")
+                                .object_mut()
+                        })
+                        .collect::<Vec<_>>();
+                    Either::Left(
+                        (y_min..=y_max)
+                            .rev()
+                            .zip(column)
+                            .map(move |(y, element)| (Coordinate { x, y }, element)),
+                    )
+                }))
+            }
+            Self::Sparse(elements) => {
+                let mut elements = elements
+                    .iter_mut()
+                    .map(|(key, element)| (key.coordinate(), element))
+                    .collect::<Vec<_>>();
+                // The sort is stable, so each column stays ordered from north to south.
+                elements.sort_by_key(|(coordinate, _)| coordinate.x);
+                let mut elements = elements.into_iter().peekable();
+                Either::Right(columns.map(move |x| {
+                    let mut column = Vec::new();
+                    while let Some(element) = elements.next_if(|(coordinate, _)| coordinate.x == x)
+                    {
+                        column.push(element);
+                    }
+                    Either::Right(fill_line(
+                        (y_min..=y_max).rev().map(move |y| Coordinate { x, y }),
+                        column,
+                    ))
+                }))
+            }
+        }
+    }
+
+    /// Mutable access to the element at each coordinate, from west to east, north to south.
+    pub(super) fn cells_mut(&mut self) -> CellsMut<'_, T> {
+        match self {
+            Self::Dense { cells, .. } => Cells::Dense(
+                cells
+                    .iter_mut()
+                    .map(GridCoordinate::object_mut as fn(_) -> _),
+            ),
+            Self::Sparse(elements) => {
+                Cells::sparse(elements.iter_mut().map(with_coordinate as fn(_) -> _))
+            }
+        }
+    }
+
+    /// Consume the storage and return the element at each coordinate, from west to east, north to
+    /// south.
+    pub(super) fn into_cells(self) -> IntoCells<T> {
+        match self {
+            Self::Dense { cells, .. } => Cells::Dense(
+                cells
+                    .into_iter()
+                    .map(GridCoordinate::into_object as fn(_) -> _),
+            ),
+            Self::Sparse(elements) => {
+                Cells::sparse(elements.into_iter().map(
+                    (|(key, element)| (key.coordinate(), element)) as fn((RowMajorKey, T)) -> _,
+                ))
+            }
+        }
+    }
+
+    /// Consume the storage and return the occupied coordinates and their elements, from west to
+    /// east, north to south.
+    pub(super) fn into_elements<B: Bounded + Copy>(
+        self,
+        bounds: B,
+    ) -> impl Iterator<Item = (Coordinate, T)> {
+        match self {
+            Self::Dense { cells, .. } => Either::Left(cells.into_iter().enumerate().filter_map(
+                move |(index, cell)| {
+                    match cell {
+                        GridCoordinate::Object(element) => Some((
+                            bounds
+                                .index_to_coordinate(index as u64)
+                                .expect("every dense index is within bounds"),
+                            element,
+                        )),
+                        GridCoordinate::Empty(_) => None,
+                    }
+                },
+            )),
+            Self::Sparse(elements) => Either::Right(
+                elements
+                    .into_iter()
+                    .map(|(key, element)| (key.coordinate(), element)),
+            ),
+        }
+    }
+
+    /// Replace each element by the result of a function, in the same representation. Elements for
+    /// which the function returns `None` are removed. The first error stops the mapping.
+    pub(super) fn try_filter_map<B: Bounded, U, E>(
+        self,
+        bounds: &B,
+        mut f: impl FnMut(Coordinate, T) -> Result<Option<U>, E>,
+    ) -> Result<GridStorage<U>, E> {
+        match self {
+            Self::Dense { cells, .. } => {
+                let mut occupied = 0;
+                let cells = cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, cell)| match cell {
+                        GridCoordinate::Object(element) => {
+                            let coordinate = bounds
+                                .index_to_coordinate(index as u64)
+                                .expect("every dense index is within bounds");
+                            Ok(match f(coordinate, element)? {
+                                Some(element) => {
+                                    occupied += 1;
+                                    GridCoordinate::Object(element)
+                                }
+                                None => GridCoordinate::Empty(coordinate),
+                            })
+                        }
+                        GridCoordinate::Empty(coordinate) => Ok(GridCoordinate::Empty(coordinate)),
+                    })
+                    .collect::<Result<_, E>>()?;
+                Ok(GridStorage::Dense {
+                    cells,
+                    occupied: AtomicUsize::new(occupied),
+                })
+            }
+            Self::Sparse(elements) => {
+                let mut mapped = BTreeMap::new();
+                for (key, element) in elements {
+                    if let Some(element) = f(key.coordinate(), element)? {
+                        mapped.insert(key, element);
+                    }
+                }
+                Ok(GridStorage::Sparse(mapped))
+            }
+        }
+    }
+
+    /// Switch to dense storage, keeping all elements.
+    pub(super) fn make_dense<B: Bounded>(&mut self, bounds: &B) {
+        if let Self::Sparse(elements) = self {
+            let elements = std::mem::take(elements);
+            let mut dense = Self::dense(bounds);
+            for (key, element) in elements {
+                let index = bounds
+                    .coordinate_to_index(&key.coordinate())
+                    .expect("stored coordinates are within bounds");
+                dense.insert(index, key.coordinate(), element);
+            }
+            *self = dense;
+        }
+    }
+
+    /// Switch to sparse storage, keeping all elements.
+    pub(super) fn make_sparse<B: Bounded>(&mut self, bounds: &B) {
+        if let Self::Dense { cells, .. } = self {
+            let elements = std::mem::take(cells)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, cell)| match cell {
+                    GridCoordinate::Object(element) => Some((
+                        RowMajorKey(
+                            bounds
+                                .index_to_coordinate(index as u64)
+                                .expect("every dense index is within bounds"),
+                        ),
+                        element,
+                    )),
+                    GridCoordinate::Empty(_) => None,
+                })
+                .collect();
+            *self = Self::Sparse(elements);
+        }
+    }
+}
+
+/// The elements of a storage, visited one coordinate at a time from west to east, north to south,
+/// from either end.
+///
+/// Dense cells yield exactly one item per coordinate. Sparse cells only yield the occupied
+/// coordinates, so the caller has to provide the coordinate that it expects next.
+#[derive(Debug)]
+pub(super) enum Cells<D, S: Iterator> {
+    Dense(D),
+    Sparse {
+        elements: S,
+        /// An element taken from the front of `elements` that has not been visited yet.
+        front: Option<S::Item>,
+        /// An element taken from the back of `elements` that has not been visited yet.
+        back: Option<S::Item>,
+    },
+}
+
+pub(super) type CellsRef<'a, T> = Cells<
+    Map<slice::Iter<'a, GridCoordinate<T>>, fn(&'a GridCoordinate<T>) -> Option<&'a T>>,
+    Map<btree_map::Iter<'a, RowMajorKey, T>, fn((&'a RowMajorKey, &'a T)) -> (Coordinate, &'a T)>,
+>;
+
+pub(super) type CellsMut<'a, T> = Cells<
+    Map<slice::IterMut<'a, GridCoordinate<T>>, fn(&'a mut GridCoordinate<T>) -> Option<&'a mut T>>,
+    Map<
+        btree_map::IterMut<'a, RowMajorKey, T>,
+        fn((&'a RowMajorKey, &'a mut T)) -> (Coordinate, &'a mut T),
+    >,
+>;
+
+pub(super) type IntoCells<T> = Cells<
+    Map<std::vec::IntoIter<GridCoordinate<T>>, fn(GridCoordinate<T>) -> Option<T>>,
+    Map<btree_map::IntoIter<RowMajorKey, T>, fn((RowMajorKey, T)) -> (Coordinate, T)>,
+>;
+
+impl<D, S: Iterator> Cells<D, S> {
+    fn sparse(elements: S) -> Self {
+        Self::Sparse {
+            elements,
+            front: None,
+            back: None,
+        }
+    }
+}
+
+impl<V, D, S> Cells<D, S>
+where
+    D: DoubleEndedIterator<Item = Option<V>>,
+    S: DoubleEndedIterator<Item = (Coordinate, V)>,
+{
+    /// The element at the next coordinate from the front, which has to be `coordinate`.
+    pub(super) fn next(&mut self, coordinate: Coordinate) -> Option<V> {
+        match self {
+            Self::Dense(cells) => cells.next().flatten(),
+            Self::Sparse {
+                elements,
+                front,
+                back,
+            } => {
+                if front.is_none() {
+                    *front = elements.next();
+                }
+                // Once the elements are exhausted, the last unvisited element may have been taken
+                // from the back.
+                let next = if front.is_some() { front } else { back };
+                next.take_if(|(key, _)| *key == coordinate)
+                    .map(|(_, element)| element)
+            }
+        }
+    }
+
+    /// The element at the next coordinate from the back, which has to be `coordinate`.
+    pub(super) fn next_back(&mut self, coordinate: Coordinate) -> Option<V> {
+        match self {
+            Self::Dense(cells) => cells.next_back().flatten(),
+            Self::Sparse {
+                elements,
+                front,
+                back,
+            } => {
+                if back.is_none() {
+                    *back = elements.next_back();
+                }
+                let next = if back.is_some() { back } else { front };
+                next.take_if(|(key, _)| *key == coordinate)
+                    .map(|(_, element)| element)
+            }
+        }
+    }
+}
+
+/// The number of coordinates within the bounds.
+pub(super) fn cell_count<B: Bounded>(bounds: &B) -> u64 {
+    bounds.x_count().as_u64() * bounds.y_count().as_u64()
+}
+
+/// Each coordinate of a line along with its element, given the occupied coordinates of the line
+/// in the same order.
+fn fill_line<V>(
+    coordinates: impl Iterator<Item = Coordinate>,
+    occupied: Vec<(Coordinate, V)>,
+) -> impl Iterator<Item = (Coordinate, Option<V>)> {
+    let mut occupied = occupied.into_iter().peekable();
+    coordinates.map(move |coordinate| {
+        let element = occupied
+            .next_if(|(key, _)| *key == coordinate)
+            .map(|(_, element)| element);
+        (coordinate, element)
+    })
+}
+
+fn with_coordinate<V>((key, element): (&RowMajorKey, V)) -> (Coordinate, V) {
+    (key.coordinate(), element)
+}
+
+fn dense_index(index: u64) -> usize {
+    usize::try_from(index).expect("since index is within bounds it must fit in usize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_major_key_orders_west_to_east_north_to_south() {
+        let mut keys = vec![
+            RowMajorKey(Coordinate { x: 1, y: -1 }),
+            RowMajorKey(Coordinate { x: -1, y: 1 }),
+            RowMajorKey(Coordinate { x: 0, y: 0 }),
+            RowMajorKey(Coordinate { x: 1, y: 1 }),
+            RowMajorKey(Coordinate { x: -1, y: 0 }),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys.into_iter()
+                .map(RowMajorKey::coordinate)
+                .collect::<Vec<Coordinate>>(),
+            vec![
+                Coordinate { x: -1, y: 1 },
+                Coordinate { x: 1, y: 1 },
+                Coordinate { x: -1, y: 0 },
+                Coordinate { x: 0, y: 0 },
+                Coordinate { x: 1, y: -1 },
+            ]
+        );
+    }
+}
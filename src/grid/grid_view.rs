@@ -0,0 +1,1178 @@
+use super::Grid;
+use super::generic_grid::element_statuses;
+use super::grid_coordinate::GridCoordinate;
+use super::grid_storage::GridStorage;
+use crate::AbsoluteDirection;
+use crate::Bounds;
+use crate::Coordinate;
+use crate::GridError;
+use crate::OutOfBoundsError;
+use crate::Positioned;
+use crate::bounded::Bounded;
+use crate::bounded::MaybeOriginBounded;
+use crate::bounded::MaybeOriginCentered;
+use crate::bounded::OriginBounded;
+use crate::bounded::OriginCenteredness;
+use itertools::Either;
+use std::fmt;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// A borrowed, read-only window into a rectangular region of a [`Grid`].
+///
+/// The view is bounded by its region rather than by the grid: coordinates outside the region are
+/// out of bounds, even if they are within the grid. Coordinates are not translated; a view keeps
+/// using the coordinates of the grid it was created from.
+///
+/// # Examples
+/// ```
+/// use tudi::Bounds;
+/// use tudi::Coordinate;
+/// use tudi::grid;
+/// let mut grid = grid!(5, 5);
+/// grid.store_element(&Coordinate { x: 1, y: 1 }, ());
+/// grid.store_element(&Coordinate { x: -2, y: -2 }, ());
+///
+/// let view = grid.view(&Bounds::from_boundaries(0, 2, 0, 2)).unwrap();
+/// assert!(view.element(&Coordinate { x: 1, y: 1 }).is_ok());
+/// assert!(view.element(&Coordinate { x: -2, y: -2 }).is_err());
+/// assert_eq!(view.element_statuses(), "...\n.#.\n...");
+/// ```
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    bounds: Bounds,
+}
+
+/// A borrowed, mutable window into a rectangular region of a [`Grid`].
+///
+/// Elements can only be stored, changed and removed within the region of the view. A view can be
+/// split into disjoint views, for instance to let several threads mutate separate parts of one
+/// grid at the same time.
+///
+/// # Examples
+/// ```
+/// use tudi::Coordinate;
+/// use tudi::bounded::Bounded;
+/// use tudi::grid;
+/// let mut grid = grid!(4, 4);
+/// let mut view = grid.view_mut(&grid.bounds()).unwrap();
+///
+/// std::thread::scope(|scope| {
+///     for mut quadrant in view.split_quadrants(Coordinate { x: 1, y: 1 }).unwrap() {
+///         scope.spawn(move || {
+///             let corner = quadrant.southwest_corner();
+///             quadrant.store_element(&corner, ()).unwrap();
+///         });
+///     }
+/// });
+/// drop(view);
+///
+/// assert_eq!(grid.iter_elements_new().count(), 4);
+/// ```
+pub struct GridViewMut<'a, T> {
+    bounds: Bounds,
+    cells: ViewCells<'a, T>,
+}
+
+/// Where a [`GridViewMut`] finds its elements.
+enum ViewCells<'a, T> {
+    /// The grid that the view was created from. Changes go through the grid, which keeps its
+    /// storage and speed cache up to date in either representation, but leaves the performance
+    /// tuning to the view.
+    ///
+    /// Once a view of a sparsely stored grid is split, the elements of its region are moved out
+    /// of the grid into dense storage with the bounds of the view, which the splits divide into
+    /// rows. They are moved back into the grid when the view is dropped.
+    Grid {
+        grid: &'a mut Grid<T>,
+        region: Option<GridStorage<T>>,
+    },
+    /// The cells of each row of a split, from north to south, and the occupancy count of the
+    /// dense storage that the cells belong to.
+    Rows {
+        rows: Rows<'a, T>,
+        occupied: &'a AtomicUsize,
+    },
+}
+
+/// The cells of each row of a region, from north to south.
+type Rows<'a, T> = Vec<&'a mut [GridCoordinate<T>]>;
+
+impl<T> Grid<T> {
+    /// A read-only view into a region of the grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any part of the region is out of bounds.
+    pub fn view<B: Bounded>(&self, region: &B) -> Result<GridView<'_, T>, OutOfBoundsError> {
+        let bounds = self.check_region(region)?;
+        Ok(GridView { grid: self, bounds })
+    }
+
+    /// A mutable view into a region of the grid.
+    ///
+    /// The view works on the grid in either representation. The performance tuning of the grid is
+    /// applied once the view is dropped, so the storage keeps its representation while the view
+    /// exists.
+    ///
+    /// Splitting a view of a sparsely stored grid allocates an entry for every coordinate of the
+    /// view, but not of the rest of the grid, until the view is dropped. The elements of the view
+    /// are lost if the view is leaked, for instance with [`std::mem::forget`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any part of the region is out of bounds.
+    pub fn view_mut<B: Bounded>(
+        &mut self,
+        region: &B,
+    ) -> Result<GridViewMut<'_, T>, OutOfBoundsError> {
+        let bounds = self.check_region(region)?;
+        Ok(GridViewMut {
+            bounds,
+            cells: ViewCells::Grid {
+                grid: self,
+                region: None,
+            },
+        })
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// The bounds of the view.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// A reference to an element in the view.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is outside of the view.
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    pub fn element<C: Positioned>(&self, coordinate: &C) -> Result<&'a T, GridError> {
+        check_coordinate(&self.bounds, coordinate)?;
+        self.grid
+            .element_unchecked(coordinate)
+            .ok_or(GridError::UnoccupiedError(*coordinate.position()))
+    }
+
+    /// The element at a coordinate, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinate is outside of the view.
+    pub fn element_unchecked<C: Positioned>(&self, coordinate: &C) -> Option<&'a T> {
+        assert!(self.is_within_bounds(coordinate));
+        self.grid.element_unchecked(coordinate)
+    }
+
+    /// All coordinates of the view along with their elements, from west to east, north to south.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, Option<&'a T>)> + 'a {
+        self.grid.storage.region(self.grid.bounds, self.bounds)
+    }
+
+    /// The occupied coordinates of the view along with their elements, from west to east, north
+    /// to south.
+    pub fn iter_elements(&self) -> impl Iterator<Item = (Coordinate, &'a T)> + 'a {
+        self.iter()
+            .filter_map(|(coordinate, element)| Some((coordinate, element?)))
+    }
+
+    /// A string where '#' marks a occupied element and '.' marks an empty element, with one line
+    /// for each row of the view.
+    pub fn element_statuses(&self) -> String {
+        element_statuses(self, self.iter())
+    }
+
+    /// Split the view into the rows north of and including `y`, and the rows south of `y`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if either part would be empty.
+    pub fn split_at_row(&self, y: i32) -> Result<(Self, Self), OutOfBoundsError> {
+        let (north, south) = split_bounds_at_row(&self.bounds, y)?;
+        Ok((self.with_bounds(north), self.with_bounds(south)))
+    }
+
+    /// Split the view into the columns west of `x`, and the columns east of and including `x`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if either part would be empty.
+    pub fn split_at_column(&self, x: i32) -> Result<(Self, Self), OutOfBoundsError> {
+        let (west, east) = split_bounds_at_column(&self.bounds, x)?;
+        Ok((self.with_bounds(west), self.with_bounds(east)))
+    }
+
+    /// Split the view into its northwest, northeast, southwest and southeast quadrants. The center
+    /// is the southwest corner of the northeast quadrant.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any quadrant would be empty.
+    pub fn split_quadrants(&self, center: Coordinate) -> Result<[Self; 4], OutOfBoundsError> {
+        let (north, south) = self.split_at_row(center.y)?;
+        let (northwest, northeast) = north.split_at_column(center.x)?;
+        let (southwest, southeast) = south.split_at_column(center.x)?;
+        Ok([northwest, northeast, southwest, southeast])
+    }
+
+    fn with_bounds(&self, bounds: Bounds) -> Self {
+        Self {
+            grid: self.grid,
+            bounds,
+        }
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> OriginCenteredness for GridView<'_, T> {
+    type Distinguisher = MaybeOriginCentered;
+}
+
+impl<T> MaybeOriginBounded for GridView<'_, T> {
+    fn x_min(&self) -> i32 {
+        self.bounds.x_min_boundary()
+    }
+
+    fn x_max(&self) -> i32 {
+        self.bounds.x_max_boundary()
+    }
+
+    fn y_min(&self) -> i32 {
+        self.bounds.y_min_boundary()
+    }
+
+    fn y_max(&self) -> i32 {
+        self.bounds.y_max_boundary()
+    }
+}
+
+impl<T> fmt::Debug for GridView<'_, T> {
+    /// The bounds of the view followed by its [element statuses](GridView::element_statuses).
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "GridView {:?}", self.bounds)?;
+        formatter.write_str(&self.element_statuses())
+    }
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// The bounds of the view.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// A reference to an element in the view.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is outside of the view.
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    pub fn element<C: Positioned>(&self, coordinate: &C) -> Result<&T, GridError> {
+        check_coordinate(&self.bounds, coordinate)?;
+        self.element_unchecked(coordinate)
+            .ok_or(GridError::UnoccupiedError(*coordinate.position()))
+    }
+
+    /// The element at a coordinate, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinate is outside of the view.
+    pub fn element_unchecked<C: Positioned>(&self, coordinate: &C) -> Option<&T> {
+        assert!(self.is_within_bounds(coordinate));
+        match &self.cells {
+            ViewCells::Grid { grid, region: None } => grid.element_unchecked(coordinate),
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => region.get(self.region_index(coordinate), *coordinate.position()),
+            ViewCells::Rows { rows, .. } => {
+                let (row, column) = self.cell_position(coordinate);
+                rows[row][column].object()
+            }
+        }
+    }
+
+    /// A mutable reference to an element in the view.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is outside of the view.
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    pub fn get_mut_element<C: Positioned>(&mut self, coordinate: &C) -> Result<&mut T, GridError> {
+        check_coordinate(&self.bounds, coordinate)?;
+        let (row, column) = self.cell_position(coordinate);
+        let index = self.region_index(coordinate);
+        match &mut self.cells {
+            ViewCells::Grid { grid, region: None } => grid.get_mut_element(coordinate),
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => region
+                .get_mut(index, *coordinate.position())
+                .ok_or(GridError::UnoccupiedError(*coordinate.position())),
+            ViewCells::Rows { rows, .. } => rows[row][column]
+                .object_mut()
+                .ok_or(GridError::UnoccupiedError(*coordinate.position())),
+        }
+    }
+
+    /// Store an element, returning the element that was previously stored at the coordinate.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is outside of the view.
+    pub fn store_element<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        element: T,
+    ) -> Result<Option<T>, GridError> {
+        check_coordinate(&self.bounds, coordinate)?;
+        let (row, column) = self.cell_position(coordinate);
+        let index = self.region_index(coordinate);
+        match &mut self.cells {
+            ViewCells::Grid { grid, region: None } => {
+                grid.store_element_without_tuning(coordinate, element)
+            }
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => Ok(region.insert(index, *coordinate.position(), element)),
+            ViewCells::Rows { rows, occupied } => {
+                let previous =
+                    std::mem::replace(&mut rows[row][column], GridCoordinate::Object(element))
+                        .into_object();
+                if previous.is_none() {
+                    occupied.fetch_add(1, Ordering::Relaxed);
+                }
+                Ok(previous)
+            }
+        }
+    }
+
+    /// Remove an element, leaving an empty coordinate in its place.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is outside of the view.
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    pub fn remove_element<C: Positioned>(&mut self, coordinate: &C) -> Result<T, GridError> {
+        check_coordinate(&self.bounds, coordinate)?;
+        let position = *coordinate.position();
+        let (row, column) = self.cell_position(coordinate);
+        let index = self.region_index(coordinate);
+        match &mut self.cells {
+            ViewCells::Grid { grid, region: None } => {
+                grid.remove_element_without_tuning(coordinate)
+            }
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => region
+                .remove(index, position)
+                .ok_or(GridError::UnoccupiedError(position)),
+            ViewCells::Rows { rows, occupied } => {
+                let cell = &mut rows[row][column];
+                if cell.object().is_none() {
+                    return Err(GridError::UnoccupiedError(position));
+                }
+
+                let element = std::mem::replace(cell, GridCoordinate::Empty(position))
+                    .into_object()
+                    .expect("the cell is occupied");
+                occupied.fetch_sub(1, Ordering::Relaxed);
+                Ok(element)
+            }
+        }
+    }
+
+    /// All coordinates of the view along with their elements, from west to east, north to south.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, Option<&T>)> {
+        match &self.cells {
+            ViewCells::Grid { grid, region: None } => {
+                Either::Left(Either::Left(grid.storage.region(grid.bounds, self.bounds)))
+            }
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => Either::Left(Either::Right(region.region(self.bounds, self.bounds))),
+            ViewCells::Rows { rows, .. } => {
+                let (x_min, y_max) = (self.x_min_boundary(), self.y_max_boundary());
+                Either::Right(rows.iter().zip(0..).flat_map(move |(row, row_index)| {
+                    row.iter().zip(0..).map(move |(cell, column_index)| {
+                        (
+                            Coordinate {
+                                x: x_min + column_index,
+                                y: y_max - row_index,
+                            },
+                            cell.object(),
+                        )
+                    })
+                }))
+            }
+        }
+    }
+
+    /// Mutable access to all coordinates of the view, from west to east, north to south.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coordinate, Option<&mut T>)> {
+        let (x_min, y_max) = (self.x_min_boundary(), self.y_max_boundary());
+        let bounds = self.bounds;
+        match &mut self.cells {
+            ViewCells::Grid { grid, region: None } => {
+                let grid = &mut **grid;
+                Either::Left(Either::Left(grid.storage.region_mut(&grid.bounds, bounds)))
+            }
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => Either::Left(Either::Right(region.region_mut(&bounds, bounds))),
+            ViewCells::Rows { rows, .. } => {
+                Either::Right(rows.iter_mut().zip(0..).flat_map(move |(row, row_index)| {
+                    row.iter_mut().zip(0..).map(move |(cell, column_index)| {
+                        (
+                            Coordinate {
+                                x: x_min + column_index,
+                                y: y_max - row_index,
+                            },
+                            cell.object_mut(),
+                        )
+                    })
+                }))
+            }
+        }
+    }
+
+    /// The occupied coordinates of the view along with their elements, from west to east, north
+    /// to south.
+    pub fn iter_elements(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.iter()
+            .filter_map(|(coordinate, element)| Some((coordinate, element?)))
+    }
+
+    /// A string where '#' marks a occupied element and '.' marks an empty element, with one line
+    /// for each row of the view.
+    pub fn element_statuses(&self) -> String {
+        element_statuses(self, self.iter())
+    }
+
+    /// Split the view into the rows north of and including `y`, and the rows south of `y`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if either part would be empty.
+    pub fn split_at_row(
+        &mut self,
+        y: i32,
+    ) -> Result<(GridViewMut<'_, T>, GridViewMut<'_, T>), OutOfBoundsError> {
+        let (north_bounds, south_bounds) = split_bounds_at_row(&self.bounds, y)?;
+        let (mut north, occupied) = self.rows_mut();
+        let south = north.split_off(north_bounds.y_count().as_u64() as usize);
+        Ok((
+            Self::child(north_bounds, north, occupied),
+            Self::child(south_bounds, south, occupied),
+        ))
+    }
+
+    /// Split the view into the columns west of `x`, and the columns east of and including `x`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if either part would be empty.
+    pub fn split_at_column(
+        &mut self,
+        x: i32,
+    ) -> Result<(GridViewMut<'_, T>, GridViewMut<'_, T>), OutOfBoundsError> {
+        let (west_bounds, east_bounds) = split_bounds_at_column(&self.bounds, x)?;
+        let west_count = west_bounds.x_count().as_u64() as usize;
+        let (rows, occupied) = self.rows_mut();
+        let (west, east) = rows
+            .into_iter()
+            .map(|row| row.split_at_mut(west_count))
+            .unzip();
+        Ok((
+            Self::child(west_bounds, west, occupied),
+            Self::child(east_bounds, east, occupied),
+        ))
+    }
+
+    /// Split the view into its northwest, northeast, southwest and southeast quadrants. The center
+    /// is the southwest corner of the northeast quadrant.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any quadrant would be empty.
+    pub fn split_quadrants(
+        &mut self,
+        center: Coordinate,
+    ) -> Result<[GridViewMut<'_, T>; 4], OutOfBoundsError> {
+        let (north_bounds, south_bounds) = split_bounds_at_row(&self.bounds, center.y)?;
+        let (west_bounds, east_bounds) = split_bounds_at_column(&self.bounds, center.x)?;
+        let north_count = north_bounds.y_count().as_u64() as usize;
+        let west_count = west_bounds.x_count().as_u64() as usize;
+        let (rows, occupied) = self.rows_mut();
+
+        let [mut northwest, mut northeast, mut southwest, mut southeast] =
+            [(); 4].map(|()| Vec::new());
+        for (index, row) in rows.into_iter().enumerate() {
+            let (west, east) = row.split_at_mut(west_count);
+            if index < north_count {
+                northwest.push(west);
+                northeast.push(east);
+            } else {
+                southwest.push(west);
+                southeast.push(east);
+            }
+        }
+
+        let quadrant_bounds = |horizontal: &Bounds, vertical: &Bounds| {
+            Bounds::from_boundaries(
+                horizontal.x_min_boundary(),
+                horizontal.x_max_boundary(),
+                vertical.y_min_boundary(),
+                vertical.y_max_boundary(),
+            )
+        };
+        Ok([
+            Self::child(
+                quadrant_bounds(&west_bounds, &north_bounds),
+                northwest,
+                occupied,
+            ),
+            Self::child(
+                quadrant_bounds(&east_bounds, &north_bounds),
+                northeast,
+                occupied,
+            ),
+            Self::child(
+                quadrant_bounds(&west_bounds, &south_bounds),
+                southwest,
+                occupied,
+            ),
+            Self::child(
+                quadrant_bounds(&east_bounds, &south_bounds),
+                southeast,
+                occupied,
+            ),
+        ])
+    }
+
+    /// The cells of each row of the view, from north to south, and the occupancy count of the
+    /// dense storage that they belong to.
+    ///
+    /// The elements of a view of a sparsely stored grid are moved into dense storage for the
+    /// region of the view first.
+    fn rows_mut(&mut self) -> (Rows<'_, T>, &AtomicUsize) {
+        let bounds = self.bounds;
+        let width = bounds.x_count().as_u64() as usize;
+        if let ViewCells::Grid { grid, region } = &mut self.cells
+            && region.is_none()
+            && !grid.storage.is_dense()
+        {
+            *region = Some(take_region(grid, bounds));
+        }
+
+        match &mut self.cells {
+            ViewCells::Grid { grid, region: None } => {
+                let grid = &mut **grid;
+                let x_count = OriginBounded::x_count(grid).as_u64() as usize;
+                let first_row = grid.y_max_boundary().abs_diff(bounds.y_max_boundary()) as usize;
+                let first_column = bounds.x_min_boundary().abs_diff(grid.x_min_boundary()) as usize;
+                let height = bounds.y_count().as_u64() as usize;
+
+                let (cells, occupied) = grid
+                    .storage
+                    .dense_parts_mut()
+                    .expect("a sparsely stored grid has a region");
+                // The splits change cells without going through the grid. The grid does without
+                // its occupancy bitmap until the performance tuning is applied again.
+                grid.speed_cache = None;
+                let rows = cells
+                    .chunks_mut(x_count)
+                    .skip(first_row)
+                    .take(height)
+                    .map(|row| &mut row[first_column..first_column + width])
+                    .collect();
+                (rows, occupied)
+            }
+            ViewCells::Grid {
+                region: Some(region),
+                ..
+            } => {
+                let (cells, occupied) = region
+                    .dense_parts_mut()
+                    .expect("the region is stored densely");
+                (cells.chunks_mut(width).collect(), occupied)
+            }
+            ViewCells::Rows { rows, occupied } => {
+                (rows.iter_mut().map(|row| &mut **row).collect(), *occupied)
+            }
+        }
+    }
+
+    fn child<'b>(
+        bounds: Bounds,
+        rows: Rows<'b, T>,
+        occupied: &'b AtomicUsize,
+    ) -> GridViewMut<'b, T> {
+        GridViewMut {
+            bounds,
+            cells: ViewCells::Rows { rows, occupied },
+        }
+    }
+
+    /// The index of a coordinate in the dense storage of the region of the view.
+    fn region_index<C: Positioned>(&self, coordinate: &C) -> u64 {
+        let (row, column) = self.cell_position(coordinate);
+        (row * self.bounds.x_count().as_u64() as usize + column) as u64
+    }
+
+    /// The row and column of a coordinate within the view.
+    fn cell_position<C: Positioned>(&self, coordinate: &C) -> (usize, usize) {
+        (
+            self.y_max_boundary().abs_diff(coordinate.y_coordinate()) as usize,
+            coordinate.x_coordinate().abs_diff(self.x_min_boundary()) as usize,
+        )
+    }
+}
+
+impl<T> Drop for GridViewMut<'_, T> {
+    /// Move the elements of a split region of a sparsely stored grid back into the grid, and apply
+    /// the performance tuning of the grid, which is suspended while the view exists, since the
+    /// view and its splits may have changed its occupancy. This also rebuilds the occupancy bitmap
+    /// of a [`PerformanceTuning::Speed`](crate::PerformanceTuning::Speed) grid.
+    fn drop(&mut self) {
+        if let ViewCells::Grid { grid, region } = &mut self.cells {
+            if let Some(region) = region.take() {
+                for (coordinate, element) in region.into_elements(self.bounds) {
+                    grid.store_element_without_tuning(&coordinate, element)
+                        .expect("the region is within the bounds of the grid");
+                }
+            }
+            grid.apply_performance_tuning();
+        }
+    }
+}
+
+/// Move the elements of a region of a grid into dense storage with the bounds of the region.
+fn take_region<T>(grid: &mut Grid<T>, region: Bounds) -> GridStorage<T> {
+    let coordinates = grid
+        .storage
+        .region(grid.bounds, region)
+        .filter_map(|(coordinate, element)| element.map(|_| coordinate))
+        .collect::<Vec<_>>();
+    let mut storage = GridStorage::dense(&region);
+    for coordinate in coordinates {
+        let element = grid
+            .remove_element_without_tuning(&coordinate)
+            .expect("the coordinate was just found to be occupied");
+        let index = region
+            .coordinate_to_index(&coordinate)
+            .expect("the coordinate is within the region");
+        storage.insert(index, coordinate, element);
+    }
+    storage
+}
+
+impl<T> OriginCenteredness for GridViewMut<'_, T> {
+    type Distinguisher = MaybeOriginCentered;
+}
+
+impl<T> MaybeOriginBounded for GridViewMut<'_, T> {
+    fn x_min(&self) -> i32 {
+        self.bounds.x_min_boundary()
+    }
+
+    fn x_max(&self) -> i32 {
+        self.bounds.x_max_boundary()
+    }
+
+    fn y_min(&self) -> i32 {
+        self.bounds.y_min_boundary()
+    }
+
+    fn y_max(&self) -> i32 {
+        self.bounds.y_max_boundary()
+    }
+}
+
+impl<T> fmt::Debug for GridViewMut<'_, T> {
+    /// The bounds of the view followed by its [element statuses](GridViewMut::element_statuses).
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "GridViewMut {:?}", self.bounds)?;
+        formatter.write_str(&self.element_statuses())
+    }
+}
+
+fn check_coordinate<C: Positioned>(bounds: &Bounds, coordinate: &C) -> Result<(), GridError> {
+    match bounds.out_of_bounds_directions(coordinate) {
+        Some((first_direction, second_direction)) => Err(GridError::OutOfBoundsError(
+            OutOfBoundsError::new(*coordinate.position(), first_direction, second_direction),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The rows north of and including `y`, and the rows south of `y`.
+fn split_bounds_at_row(bounds: &Bounds, y: i32) -> Result<(Bounds, Bounds), OutOfBoundsError> {
+    let position = Coordinate {
+        x: bounds.x_min_boundary(),
+        y,
+    };
+    if y > bounds.y_max_boundary() {
+        Err(OutOfBoundsError::new(
+            position,
+            AbsoluteDirection::North,
+            None,
+        ))
+    } else if y <= bounds.y_min_boundary() {
+        Err(OutOfBoundsError::new(
+            position,
+            AbsoluteDirection::South,
+            None,
+        ))
+    } else {
+        let (x_min, x_max) = (bounds.x_min_boundary(), bounds.x_max_boundary());
+        Ok((
+            Bounds::from_boundaries(x_min, x_max, y, bounds.y_max_boundary()),
+            Bounds::from_boundaries(x_min, x_max, bounds.y_min_boundary(), y - 1),
+        ))
+    }
+}
+
+/// The columns west of `x`, and the columns east of and including `x`.
+fn split_bounds_at_column(bounds: &Bounds, x: i32) -> Result<(Bounds, Bounds), OutOfBoundsError> {
+    let position = Coordinate {
+        x,
+        y: bounds.y_max_boundary(),
+    };
+    if x > bounds.x_max_boundary() {
+        Err(OutOfBoundsError::new(
+            position,
+            AbsoluteDirection::East,
+            None,
+        ))
+    } else if x <= bounds.x_min_boundary() {
+        Err(OutOfBoundsError::new(
+            position,
+            AbsoluteDirection::West,
+            None,
+        ))
+    } else {
+        let (y_min, y_max) = (bounds.y_min_boundary(), bounds.y_max_boundary());
+        Ok((
+            Bounds::from_boundaries(bounds.x_min_boundary(), x - 1, y_min, y_max),
+            Bounds::from_boundaries(x, bounds.x_max_boundary(), y_min, y_max),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+    use crate::PerformanceTuning;
+    use crate::grid::generic_grid::tests::TUNINGS;
+    use crate::grid::generic_grid::tests::checkered_grid;
+
+    fn assert_occupancy_is_consistent<T>(grid: &Grid<T>) {
+        if let GridStorage::Dense { cells, occupied } = &grid.storage {
+            assert_eq!(
+                occupied.load(Ordering::Relaxed),
+                cells.iter().filter(|cell| cell.object().is_some()).count()
+            );
+        }
+        assert_eq!(
+            grid.iter_elements_new()
+                .map(|(coordinate, _)| coordinate)
+                .collect::<Vec<_>>(),
+            grid.iter_new()
+                .filter(|(_, element)| element.is_some())
+                .map(|(coordinate, _)| coordinate)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn view_is_bounded_by_its_region() {
+        for tuning in TUNINGS {
+            let grid = checkered_grid(6, 5, tuning);
+            let region = Bounds::from_boundaries(-1, 1, 0, 2);
+            let view = grid.view(&region).unwrap();
+
+            assert_eq!(view.bounds(), region);
+            assert_eq!(view.x_count().as_u64(), 3);
+            assert_eq!(view.element(&Coordinate { x: 1, y: 1 }), Ok(&11));
+            assert_eq!(
+                view.element(&Coordinate { x: 1, y: 0 }),
+                Err(GridError::UnoccupiedError(Coordinate { x: 1, y: 0 }))
+            );
+            assert!(matches!(
+                view.element(&Coordinate { x: -2, y: 0 }),
+                Err(GridError::OutOfBoundsError(_))
+            ));
+            assert_eq!(view.element_statuses(), ".#.\n#.#\n.#.");
+            assert!(view.iter().eq(grid.region(&region).unwrap()));
+            assert_eq!(view.iter_elements().count(), 4);
+        }
+    }
+
+    #[test]
+    fn view_out_of_bounds() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Auto);
+        assert!(grid.view(&Bounds::from_boundaries(-3, 0, 0, 0)).is_err());
+        assert!(grid.view(&Bounds::from_boundaries(0, 0, 0, 3)).is_err());
+        assert!(grid.view_mut(&Bounds::from_boundaries(0, 4, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn view_quadrants_cover_the_view() {
+        let grid = checkered_grid(6, 5, PerformanceTuning::Auto);
+        let view = grid.view(&grid.bounds()).unwrap();
+        let quadrants = view.split_quadrants(Coordinate { x: 1, y: 0 }).unwrap();
+
+        assert_eq!(
+            quadrants.map(|quadrant| quadrant.bounds()),
+            [
+                Bounds::from_boundaries(-2, 0, 0, 2),
+                Bounds::from_boundaries(1, 3, 0, 2),
+                Bounds::from_boundaries(-2, 0, -2, -1),
+                Bounds::from_boundaries(1, 3, -2, -1),
+            ]
+        );
+        assert_eq!(
+            quadrants
+                .iter()
+                .map(|quadrant| quadrant.iter_elements().count())
+                .sum::<usize>(),
+            grid.iter_elements_new().count()
+        );
+    }
+
+    #[test]
+    fn split_errors() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Auto);
+        let view = grid.view(&Bounds::from_boundaries(-1, 1, -1, 1)).unwrap();
+        assert_eq!(
+            view.split_at_row(-1).unwrap_err(),
+            OutOfBoundsError::new(Coordinate { x: -1, y: -1 }, AbsoluteDirection::South, None)
+        );
+        assert_eq!(
+            view.split_at_column(2).unwrap_err(),
+            OutOfBoundsError::new(Coordinate { x: 2, y: 1 }, AbsoluteDirection::East, None)
+        );
+        assert!(view.split_at_row(1).is_ok());
+        assert!(view.split_at_column(0).is_ok());
+
+        let mut view = grid
+            .view_mut(&Bounds::from_boundaries(-1, 1, -1, 1))
+            .unwrap();
+        assert!(view.split_at_row(2).is_err());
+        assert!(view.split_at_column(-1).is_err());
+        assert!(view.split_quadrants(Coordinate { x: 0, y: -1 }).is_err());
+    }
+
+    #[test]
+    fn view_mut_is_confined_to_its_region() {
+        for tuning in TUNINGS {
+            let mut grid = checkered_grid(6, 5, tuning);
+            let mut view = grid.view_mut(&Bounds::from_boundaries(0, 1, 0, 1)).unwrap();
+
+            assert_eq!(view.store_element(&Coordinate { x: 1, y: 0 }, 1), Ok(None));
+            assert_eq!(
+                view.store_element(&Coordinate { x: 1, y: 1 }, 2),
+                Ok(Some(11))
+            );
+            assert_eq!(view.remove_element(&Coordinate { x: 0, y: 0 }), Ok(0));
+            assert_eq!(
+                view.remove_element(&Coordinate { x: 0, y: 0 }),
+                Err(GridError::UnoccupiedError(Coordinate { x: 0, y: 0 }))
+            );
+            *view.get_mut_element(&Coordinate { x: 1, y: 0 }).unwrap() += 2;
+            assert!(matches!(
+                view.store_element(&Coordinate { x: 2, y: 0 }, 0),
+                Err(GridError::OutOfBoundsError(_))
+            ));
+            assert!(matches!(
+                view.remove_element(&Coordinate { x: -1, y: -1 }),
+                Err(GridError::OutOfBoundsError(_))
+            ));
+            assert_eq!(view.element_statuses(), ".#\n.#");
+            drop(view);
+
+            assert_eq!(grid.element(&Coordinate { x: 1, y: 0 }), Ok(&3));
+            assert_eq!(grid.element(&Coordinate { x: 1, y: 1 }), Ok(&2));
+            assert!(grid.element(&Coordinate { x: 0, y: 0 }).is_err());
+            assert_eq!(grid.element(&Coordinate { x: -1, y: -1 }), Ok(&-11));
+            assert_occupancy_is_consistent(&grid);
+        }
+    }
+
+    #[test]
+    fn view_mut_iterates_like_the_grid() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Auto);
+        let region = Bounds::from_boundaries(-2, 0, -1, 2);
+        let expected = grid
+            .region(&region)
+            .unwrap()
+            .map(|(coordinate, element)| (coordinate, element.copied()))
+            .collect::<Vec<_>>();
+
+        let mut view = grid.view_mut(&region).unwrap();
+        assert!(
+            view.iter()
+                .map(|(coordinate, element)| (coordinate, element.copied()))
+                .eq(expected.iter().copied())
+        );
+        for (_, element) in view.iter_mut() {
+            if let Some(element) = element {
+                *element *= 2;
+            }
+        }
+        drop(view);
+
+        for (coordinate, element) in expected {
+            assert_eq!(
+                grid.element_unchecked(&coordinate).copied(),
+                element.map(|element| element * 2)
+            );
+        }
+    }
+
+    #[test]
+    fn quadrants_are_mutated_from_threads() {
+        for tuning in [PerformanceTuning::Speed, PerformanceTuning::Auto] {
+            let mut grid = checkered_grid(6, 5, tuning);
+            let mut view = grid.view_mut(&grid.bounds()).unwrap();
+            std::thread::scope(|scope| {
+                for mut quadrant in view.split_quadrants(Coordinate { x: 0, y: 1 }).unwrap() {
+                    scope.spawn(move || {
+                        let coordinates = quadrant
+                            .iter()
+                            .map(|(coordinate, _)| coordinate)
+                            .collect::<Vec<_>>();
+                        for coordinate in coordinates {
+                            if quadrant.remove_element(&coordinate).is_err() {
+                                quadrant.store_element(&coordinate, 1).unwrap();
+                            }
+                        }
+                    });
+                }
+            });
+            drop(view);
+
+            assert_eq!(
+                grid.element_statuses(),
+                ".#.#.#\n#.#.#.\n.#.#.#\n#.#.#.\n.#.#.#"
+            );
+            assert_eq!(grid.iter_elements_new().count(), 15);
+            assert_occupancy_is_consistent(&grid);
+        }
+    }
+
+    #[test]
+    fn nested_splits_report_their_occupancy() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Speed);
+        let mut view = grid.view_mut(&grid.bounds()).unwrap();
+        let (mut north, mut south) = view.split_at_row(1).unwrap();
+        let (mut west, _) = north.split_at_column(0).unwrap();
+        west.store_element(&Coordinate { x: -1, y: 2 }, 0).unwrap();
+        west.store_element(&Coordinate { x: -2, y: 1 }, 0).unwrap();
+        drop(west);
+        drop(north);
+        south.remove_element(&Coordinate { x: 0, y: 0 }).unwrap();
+        drop(south);
+        drop(view);
+
+        assert_eq!(grid.iter_elements_new().count(), 16);
+        assert_eq!(grid.empty_rows(), Vec::<i32>::new());
+        assert_occupancy_is_consistent(&grid);
+    }
+
+    #[test]
+    fn forgotten_splits_keep_the_occupancy() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Speed);
+        let mut view = grid.view_mut(&grid.bounds()).unwrap();
+        let (mut north, mut south) = view.split_at_row(1).unwrap();
+        north.store_element(&Coordinate { x: -1, y: 2 }, 0).unwrap();
+        south.remove_element(&Coordinate { x: 0, y: 0 }).unwrap();
+        std::mem::forget(north);
+        std::mem::forget(south);
+        std::mem::forget(view);
+
+        assert_eq!(grid.iter_elements_new().count(), 15);
+        assert_occupancy_is_consistent(&grid);
+    }
+
+    #[test]
+    fn view_mut_keeps_sparse_storage() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Memory);
+        let mut view = grid.view_mut(&Bounds::from_boundaries(0, 1, 0, 1)).unwrap();
+        assert_eq!(view.remove_element(&Coordinate { x: 0, y: 0 }), Ok(0));
+        assert_eq!(view.store_element(&Coordinate { x: 1, y: 0 }, 10), Ok(None));
+        for (_, element) in view.iter_mut() {
+            if let Some(element) = element {
+                *element += 1;
+            }
+        }
+        assert_eq!(view.element_statuses(), ".#\n.#");
+        drop(view);
+
+        assert!(!grid.storage.is_dense());
+        assert_eq!(grid.element(&Coordinate { x: 1, y: 0 }), Ok(&11));
+        assert_eq!(grid.element(&Coordinate { x: 1, y: 1 }), Ok(&12));
+    }
+
+    #[test]
+    fn splits_of_a_sparse_grid_move_the_view_back_on_drop() {
+        let mut grid = checkered_grid(6, 5, PerformanceTuning::Memory);
+        let mut view = grid
+            .view_mut(&Bounds::from_boundaries(-1, 2, -1, 2))
+            .unwrap();
+        let (mut north, south) = view.split_at_row(1).unwrap();
+        assert_eq!(north.store_element(&Coordinate { x: 0, y: 1 }, 7), Ok(None));
+        assert_eq!(north.element(&Coordinate { x: 1, y: 1 }), Ok(&11));
+        drop((north, south));
+        assert_eq!(view.element(&Coordinate { x: 0, y: 1 }), Ok(&7));
+        assert_eq!(view.remove_element(&Coordinate { x: 0, y: 0 }), Ok(0));
+        *view.get_mut_element(&Coordinate { x: 2, y: 2 }).unwrap() += 1;
+        assert_eq!(view.iter_elements().count(), 8);
+        assert!(view.split_quadrants(Coordinate { x: 1, y: 1 }).is_ok());
+        assert_eq!(view.element_statuses(), ".#.#\n###.\n...#\n#.#.");
+        drop(view);
+
+        assert!(!grid.storage.is_dense());
+        assert_eq!(grid.element(&Coordinate { x: 0, y: 1 }), Ok(&7));
+        assert_eq!(grid.element(&Coordinate { x: 2, y: 2 }), Ok(&23));
+        assert!(grid.element(&Coordinate { x: 0, y: 0 }).is_err());
+        assert_eq!(grid.iter_elements_new().count(), 15);
+    }
+
+    #[test]
+    fn splitting_a_small_view_of_a_huge_sparse_grid() {
+        let count = AxisCount::try_from(100_000).unwrap();
+        let mut grid: Grid<u32> =
+            Grid::with_count(count, count).with_performance_tuning(PerformanceTuning::Memory);
+        grid.store_element(
+            &Coordinate {
+                x: 40_000,
+                y: 40_000,
+            },
+            1,
+        )
+        .unwrap();
+        grid.store_element(&Coordinate { x: -40_000, y: 0 }, 2)
+            .unwrap();
+
+        let region = Bounds::from_boundaries(39_999, 40_000, 39_999, 40_000);
+        let mut view = grid.view_mut(&region).unwrap();
+        std::thread::scope(|scope| {
+            for mut quadrant in view
+                .split_quadrants(Coordinate {
+                    x: 40_000,
+                    y: 40_000,
+                })
+                .unwrap()
+            {
+                scope.spawn(move || {
+                    let corner = quadrant.southwest_corner();
+                    match quadrant.get_mut_element(&corner) {
+                        Ok(element) => *element += 10,
+                        Err(_) => assert_eq!(quadrant.store_element(&corner, 3), Ok(None)),
+                    }
+                });
+            }
+        });
+        drop(view);
+
+        assert!(!grid.storage.is_dense());
+        assert_eq!(grid.iter_elements_new().count(), 5);
+        assert_eq!(
+            grid.element(&Coordinate {
+                x: 40_000,
+                y: 40_000
+            }),
+            Ok(&11)
+        );
+        assert_eq!(grid.element(&Coordinate { x: -40_000, y: 0 }), Ok(&2));
+        assert_eq!(
+            grid.element(&Coordinate {
+                x: 39_999,
+                y: 39_999
+            }),
+            Ok(&3)
+        );
+    }
+
+    #[test]
+    fn sparse_auto_grid_splits_into_quadrants_across_threads() {
+        let mut grid: Grid<u32> = Grid::with_count(
+            AxisCount::try_from(100).unwrap(),
+            AxisCount::try_from(100).unwrap(),
+        );
+        grid.store_element(&Coordinate { x: 20, y: 20 }, 0).unwrap();
+
+        for frame in 1..=3 {
+            assert!(!grid.storage.is_dense());
+            let mut view = grid.view_mut(&grid.bounds()).unwrap();
+            std::thread::scope(|scope| {
+                for mut quadrant in view.split_quadrants(Coordinate::default()).unwrap() {
+                    scope.spawn(move || {
+                        let corner = quadrant.southwest_corner();
+                        quadrant.store_element(&corner, frame).unwrap();
+                        let coordinates = quadrant
+                            .iter_elements()
+                            .map(|(coordinate, _)| coordinate)
+                            .collect::<Vec<_>>();
+                        for coordinate in coordinates {
+                            *quadrant.get_mut_element(&coordinate).unwrap() = frame;
+                        }
+                    });
+                }
+            });
+            drop(view);
+
+            assert_eq!(grid.iter_elements_new().count(), 5);
+            assert!(
+                grid.iter_elements_new()
+                    .all(|(_, element)| *element == frame)
+            );
+            assert_occupancy_is_consistent(&grid);
+        }
+        assert!(!grid.storage.is_dense());
+    }
+
+    #[test]
+    fn dropping_a_split_view_applies_the_performance_tuning() {
+        let mut grid = checkered_grid(100, 100, PerformanceTuning::Auto);
+        assert!(grid.storage.is_dense());
+
+        let mut view = grid.view_mut(&grid.bounds()).unwrap();
+        let (mut north, mut south) = view.split_at_row(0).unwrap();
+        for split in [&mut north, &mut south] {
+            let coordinates = split
+                .iter_elements()
+                .map(|(coordinate, _)| coordinate)
+                .collect::<Vec<_>>();
+            for coordinate in coordinates {
+                split.remove_element(&coordinate).unwrap();
+            }
+        }
+        drop((north, south));
+        drop(view);
+
+        assert!(!grid.storage.is_dense());
+        assert_eq!(grid.iter_elements_new().count(), 0);
+    }
+}
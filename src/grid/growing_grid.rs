@@ -0,0 +1,434 @@
+use super::Grid;
+use super::GridIterMut;
+use super::PerformanceTuning;
+use super::generic_grid::count_to_fit;
+use crate::AbsoluteDirection;
+use crate::AxisCount;
+use crate::Coordinate;
+use crate::GridError;
+use crate::OriginCenteredBounds;
+use crate::OutOfBoundsError;
+use crate::Positioned;
+use crate::WrapMode;
+use crate::bounded::Bounded;
+use crate::bounded::OriginBounded;
+use std::fmt;
+use std::ops::Deref;
+
+/// A [`Grid`] that grows when an element is stored out of bounds.
+///
+/// The grid stays origin-centered while it grows, like with [`Grid::add_row`] and
+/// [`Grid::add_column`]. When a coordinate does not fit, each axis that is too short grows to at
+/// least twice its count, so storing elements takes amortized constant time no matter how far the
+/// grid grows. An optional maximum count limits the growth.
+///
+/// A growing grid dereferences to its [`Grid`], so all methods that read the grid are available.
+/// Only the methods that cannot store an element out of bounds are available to change the grid.
+/// [`store_element`](GrowingGrid::store_element()), [`try_extend`](GrowingGrid::try_extend()),
+/// [`Extend`] and [`FromIterator`] grow the grid.
+///
+/// # Examples
+/// ```
+/// use tudi::Coordinate;
+/// use tudi::GrowingGrid;
+/// let mut crystal = GrowingGrid::new();
+/// crystal.store_element(&Coordinate { x: 0, y: 0 }, 'a').unwrap();
+/// crystal.store_element(&Coordinate { x: 5, y: -3 }, 'b').unwrap();
+///
+/// assert_eq!(crystal.element(&Coordinate { x: 5, y: -3 }), Ok(&'b'));
+/// assert!(crystal.x_count() >= 10);
+/// ```
+#[derive(Clone)]
+pub struct GrowingGrid<T> {
+    grid: Grid<T>,
+    max_bounds: Option<OriginCenteredBounds>,
+}
+
+impl<T> GrowingGrid<T> {
+    /// An empty growing grid with a single coordinate at the origin and no maximum count.
+    pub fn new() -> Self {
+        Self::from(Grid::default())
+    }
+
+    /// Limit the growth of the grid to a maximum count along each axis.
+    ///
+    /// The limit only applies to [`store_element`](GrowingGrid::store_element()); a grid that
+    /// is already larger than the limit is not shrunk.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::AxisCount;
+    /// use tudi::Coordinate;
+    /// use tudi::GrowingGrid;
+    /// let max_count = AxisCount::try_from(5).unwrap();
+    /// let mut room = GrowingGrid::new().with_max_count(max_count, max_count);
+    ///
+    /// assert!(room.store_element(&Coordinate { x: 2, y: -2 }, ()).is_ok());
+    /// assert!(room.store_element(&Coordinate { x: 3, y: 0 }, ()).is_err());
+    /// ```
+    pub fn with_max_count(mut self, x_count: AxisCount, y_count: AxisCount) -> Self {
+        self.max_bounds = Some(OriginCenteredBounds::new(x_count, y_count));
+        self
+    }
+
+    /// The bounds that the grid can grow to, or `None` if the growth is only limited by
+    /// [`AxisCount::MAX`].
+    pub fn max_bounds(&self) -> Option<OriginCenteredBounds> {
+        self.max_bounds
+    }
+
+    /// Store an element at a coordinate, growing the grid first if the coordinate is out of
+    /// bounds.
+    ///
+    /// If the coordinate was previously empty, `Ok(None)` is returned. If it contained an
+    /// element, the element is replaced and `Ok(Some(old element))` is returned. A
+    /// [toroidal](WrapMode::Toroidal) grid never grows, since every coordinate wraps into its
+    /// bounds.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the grid would have to grow past its maximum count, or if
+    /// the coordinate has `i32::MIN` as its x- or y-coordinate, since no origin-centered grid
+    /// contains it. The grid does not grow in that case.
+    pub fn store_element<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        element: T,
+    ) -> Result<Option<T>, GridError> {
+        if self.grid.wrap_mode() == WrapMode::Bounded && !self.grid.is_within_bounds(coordinate) {
+            let bounds = self.bounds_to_fit(*coordinate.position())?;
+            self.grid.grow_to(bounds);
+        }
+        self.grid.store_element(coordinate, element)
+    }
+
+    /// Store every element of an iterator at its coordinate like
+    /// [`store_element`](GrowingGrid::store_element()), growing the grid as needed.
+    ///
+    /// This is the fallible version of [`Extend::extend`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error at the first coordinate that the grid cannot grow to. The
+    /// elements before it have been stored.
+    pub fn try_extend<I: IntoIterator<Item = (Coordinate, T)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), GridError> {
+        for (coordinate, element) in iter {
+            self.store_element(&coordinate, element)?;
+        }
+        Ok(())
+    }
+
+    /// See [`Grid::get_mut_element`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds or does not contain an
+    /// element.
+    pub fn get_mut_element<C: Positioned>(&mut self, coordinate: &C) -> Result<&mut T, GridError> {
+        self.grid.get_mut_element(coordinate)
+    }
+
+    /// See [`Grid::remove_element`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds or does not contain an
+    /// element.
+    pub fn remove_element<C: Positioned>(&mut self, coordinate: &C) -> Result<T, GridError> {
+        self.grid.remove_element(coordinate)
+    }
+
+    /// See [`Grid::move_element_in_direction`]. The grid does not grow to fit the move.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the move would be out of bounds or result in a collision,
+    /// or if the coordinate does not contain an element.
+    pub fn move_element_in_direction(
+        &mut self,
+        coordinate: &Coordinate,
+        direction: AbsoluteDirection,
+    ) -> Result<Coordinate, GridError> {
+        self.grid.move_element_in_direction(coordinate, direction)
+    }
+
+    /// See [`Grid::iter_mut_new`].
+    pub fn iter_mut_new(&mut self) -> GridIterMut<'_, T> {
+        self.grid.iter_mut_new()
+    }
+
+    /// See [`Grid::iter_mut_elements_new`].
+    pub fn iter_mut_elements_new(&mut self) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        self.grid.iter_mut_elements_new()
+    }
+
+    /// See [`Grid::set_performance_tuning`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuning requires dense storage and the allocation exceeds
+    /// `isize::MAX` bytes.
+    pub fn set_performance_tuning(&mut self, performance_tuning: PerformanceTuning) {
+        self.grid.set_performance_tuning(performance_tuning);
+    }
+
+    /// See [`Grid::set_wrap_mode`].
+    pub fn set_wrap_mode(&mut self, wrap_mode: WrapMode) {
+        self.grid.set_wrap_mode(wrap_mode);
+    }
+
+    /// The grid that this growing grid wraps.
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// The bounds that the grid grows to in order to contain a coordinate.
+    fn bounds_to_fit(&self, coordinate: Coordinate) -> Result<OriginCenteredBounds, GridError> {
+        let out_of_bounds = |max_bounds: &OriginCenteredBounds| {
+            let (first_direction, second_direction) = max_bounds
+                .out_of_bounds_directions(&coordinate)
+                .expect("the coordinate is out of the maximum bounds");
+            OutOfBoundsError::new(coordinate, first_direction, second_direction)
+        };
+        let max_bounds = self
+            .max_bounds
+            .unwrap_or(OriginCenteredBounds::new(AxisCount::MAX, AxisCount::MAX));
+        if !max_bounds.is_within_bounds(&coordinate) {
+            return Err(out_of_bounds(&max_bounds).into());
+        }
+
+        let grow = |count: AxisCount, min: i32, max: i32, value: i32, max_count: AxisCount| {
+            let needed = count_to_fit(min.min(value), max.max(value))
+                .expect("the coordinate is within the maximum bounds");
+            if needed <= count {
+                count
+            } else {
+                let doubled = AxisCount::try_from(count.as_u64() * 2).unwrap_or(AxisCount::MAX);
+                needed.max(doubled).min(max_count)
+            }
+        };
+
+        Ok(OriginCenteredBounds::new(
+            grow(
+                OriginBounded::x_count(&self.grid),
+                self.grid.x_min_boundary(),
+                self.grid.x_max_boundary(),
+                coordinate.x,
+                OriginBounded::x_count(&max_bounds).max(OriginBounded::x_count(&self.grid)),
+            ),
+            grow(
+                OriginBounded::y_count(&self.grid),
+                self.grid.y_min_boundary(),
+                self.grid.y_max_boundary(),
+                coordinate.y,
+                OriginBounded::y_count(&max_bounds).max(OriginBounded::y_count(&self.grid)),
+            ),
+        ))
+    }
+}
+
+impl<T> Default for GrowingGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Grid<T>> for GrowingGrid<T> {
+    /// A growing grid that starts out as the grid, without a maximum count.
+    fn from(grid: Grid<T>) -> Self {
+        Self {
+            grid,
+            max_bounds: None,
+        }
+    }
+}
+
+impl<T> Deref for GrowingGrid<T> {
+    type Target = Grid<T>;
+
+    fn deref(&self) -> &Grid<T> {
+        &self.grid
+    }
+}
+
+impl<T> FromIterator<(Coordinate, T)> for GrowingGrid<T> {
+    /// A growing grid without a maximum count that contains every coordinate of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate has `i32::MIN` as its x- or y-coordinate, since no origin-centered
+    /// grid contains it.
+    fn from_iter<I: IntoIterator<Item = (Coordinate, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Coordinate, T)> for GrowingGrid<T> {
+    /// # Panics
+    ///
+    /// Panics if the grid cannot grow to a coordinate. See [`GrowingGrid::try_extend`] for a
+    /// fallible alternative.
+    fn extend<I: IntoIterator<Item = (Coordinate, T)>>(&mut self, iter: I) {
+        if let Err(err) = self.try_extend(iter) {
+            panic!("cannot extend growing grid: {err}");
+        }
+    }
+}
+
+impl<T> fmt::Debug for GrowingGrid<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::generic_grid::tests::TUNINGS;
+
+    #[test]
+    fn grows_to_fit_coordinates() {
+        let mut grid = GrowingGrid::new();
+        assert_eq!(grid.store_element(&Coordinate { x: -3, y: 0 }, 1), Ok(None));
+        assert_eq!(grid.x_min_boundary(), -3);
+        assert_eq!(grid.y_count(), 1);
+
+        assert_eq!(grid.store_element(&Coordinate { x: 0, y: 4 }, 2), Ok(None));
+        assert_eq!(
+            grid.store_element(&Coordinate { x: 0, y: 4 }, 3),
+            Ok(Some(2))
+        );
+        assert!(grid.y_max_boundary() >= 4);
+        assert_eq!(grid.element(&Coordinate { x: -3, y: 0 }), Ok(&1));
+        assert_eq!(grid.iter_elements_new().count(), 2);
+    }
+
+    #[test]
+    fn growth_doubles_the_counts() {
+        for tuning in TUNINGS {
+            let mut grid = GrowingGrid::from(Grid::default().with_performance_tuning(tuning));
+            let mut growths = 0;
+            for x in 0..1000 {
+                let x_count = grid.x_count();
+                grid.store_element(&Coordinate { x, y: 0 }, x).unwrap();
+                if grid.x_count() != x_count {
+                    growths += 1;
+                }
+            }
+            assert_eq!(growths, 11);
+            assert_eq!(grid.y_count(), 1);
+            assert!(
+                grid.iter_elements_new()
+                    .all(|(coordinate, x)| coordinate.x == *x)
+            );
+            assert_eq!(grid.performance_tuning(), tuning);
+        }
+    }
+
+    #[test]
+    fn maximum_count() {
+        let mut grid = GrowingGrid::new().with_max_count(
+            AxisCount::try_from(4).unwrap(),
+            AxisCount::try_from(3).unwrap(),
+        );
+        grid.store_element(&Coordinate { x: 1, y: 0 }, ()).unwrap();
+        assert_eq!(grid.x_count(), 2);
+
+        grid.store_element(&Coordinate { x: 2, y: 0 }, ()).unwrap();
+        assert_eq!(grid.x_count(), 4);
+        assert_eq!(
+            grid.store_element(&Coordinate { x: -2, y: 2 }, ()),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: -2, y: 2 },
+                AbsoluteDirection::North,
+                Some(AbsoluteDirection::West)
+            )))
+        );
+        assert_eq!(grid.x_count(), 4);
+        assert_eq!(grid.y_count(), 1);
+
+        grid.store_element(&Coordinate { x: -1, y: -1 }, ())
+            .unwrap();
+        assert_eq!(grid.y_count(), 3);
+    }
+
+    #[test]
+    fn extreme_coordinates() {
+        let mut grid =
+            GrowingGrid::from(Grid::default().with_performance_tuning(PerformanceTuning::Memory));
+        assert!(
+            grid.store_element(&Coordinate { x: i32::MIN, y: 0 }, ())
+                .is_err()
+        );
+        grid.store_element(
+            &Coordinate {
+                x: i32::MAX,
+                y: i32::MIN + 1,
+            },
+            (),
+        )
+        .unwrap();
+        // An even count fits i32::MAX, an odd count is needed for i32::MIN + 1.
+        assert_eq!(grid.x_count(), u64::from(u32::MAX) - 1);
+        assert_eq!(grid.y_count(), AxisCount::MAX);
+    }
+
+    #[test]
+    fn extend_grows_the_grid() {
+        let mut grid: GrowingGrid<i32> = [(Coordinate { x: -2, y: 1 }, 1)].into_iter().collect();
+        assert_eq!(grid.x_min_boundary(), -2);
+
+        grid.extend([
+            (Coordinate { x: 100, y: 0 }, 2),
+            (Coordinate { x: 0, y: -5 }, 3),
+        ]);
+        assert!(grid.x_max_boundary() >= 100);
+        assert_eq!(grid.element(&Coordinate { x: 100, y: 0 }), Ok(&2));
+        assert_eq!(grid.element(&Coordinate { x: -2, y: 1 }), Ok(&1));
+
+        let mut bounded = GrowingGrid::new().with_max_count(
+            AxisCount::try_from(3).unwrap(),
+            AxisCount::try_from(3).unwrap(),
+        );
+        assert!(
+            bounded
+                .try_extend([
+                    (Coordinate { x: 1, y: 1 }, 4),
+                    (Coordinate { x: 2, y: 0 }, 5)
+                ])
+                .is_err()
+        );
+        assert_eq!(bounded.iter_elements_new().count(), 1);
+    }
+
+    #[test]
+    fn forwarded_mutators() {
+        let mut grid = GrowingGrid::new();
+        grid.store_element(&Coordinate { x: 1, y: 1 }, 1).unwrap();
+        *grid.get_mut_element(&Coordinate { x: 1, y: 1 }).unwrap() += 1;
+        grid.move_element_in_direction(&Coordinate { x: 1, y: 1 }, AbsoluteDirection::West)
+            .unwrap();
+        for (_, element) in grid.iter_mut_elements_new() {
+            *element *= 10;
+        }
+        assert_eq!(grid.remove_element(&Coordinate { x: 0, y: 1 }), Ok(20));
+
+        grid.set_performance_tuning(PerformanceTuning::Memory);
+        grid.set_wrap_mode(WrapMode::Toroidal);
+        assert_eq!(grid.performance_tuning(), PerformanceTuning::Memory);
+        assert_eq!(grid.wrap_mode(), WrapMode::Toroidal);
+    }
+
+    #[test]
+    fn toroidal_grids_do_not_grow() {
+        let mut grid = GrowingGrid::from(Grid::default().with_wrap_mode(WrapMode::Toroidal));
+        grid.store_element(&Coordinate { x: 7, y: 7 }, ()).unwrap();
+        assert_eq!(grid.x_count(), 1);
+        assert!(grid.element(&Coordinate::default()).is_ok());
+    }
+}
@@ -0,0 +1,548 @@
+use super::Grid;
+use super::GridIterMut;
+use crate::AbsoluteDirection;
+use crate::AxisCount;
+use crate::Coordinate;
+use crate::GridError;
+use crate::HorizontalDirection;
+use crate::OriginCenteredBounds;
+use crate::Positioned;
+use crate::VerticalDirection;
+use crate::bounded::Bounded;
+use crate::bounded::OriginBounded;
+use crate::bounded::OriginCentered;
+use crate::bounded::OriginCenteredness;
+use std::ops::Deref;
+
+/// An operation that a [`LayeredGrid`] applies to each of its layers.
+///
+/// The operation must change the bounds of every layer in the same way, whatever the elements of
+/// the layer are. See [`LayeredGrid::transform`].
+pub trait LayerTransform {
+    fn transform<T>(&mut self, layer: &mut Grid<T>);
+}
+
+/// The grids that make up the layers of a [`LayeredGrid`].
+///
+/// This trait is implemented for tuples of up to six grids. It can also be implemented for a
+/// struct of grids, which gives the layers names.
+///
+/// # Examples
+/// ```
+/// use tudi::Grid;
+/// use tudi::LayerMut;
+/// use tudi::LayerTransform;
+/// use tudi::Layers;
+/// use tudi::OriginCenteredBounds;
+/// struct Map {
+///     terrain: Grid<char>,
+///     actors: Grid<u32>,
+/// }
+///
+/// struct MapMut<'a> {
+///     terrain: LayerMut<'a, char>,
+///     actors: LayerMut<'a, u32>,
+/// }
+///
+/// impl Layers for Map {
+///     type Mut<'a> = MapMut<'a>;
+///
+///     fn layer_bounds(&self) -> Vec<OriginCenteredBounds> {
+///         vec![self.terrain.bounds(), self.actors.bounds()]
+///     }
+///
+///     fn transform_layers<F: LayerTransform>(&mut self, transform: &mut F) {
+///         transform.transform(&mut self.terrain);
+///         transform.transform(&mut self.actors);
+///     }
+///
+///     fn layers_mut(&mut self) -> MapMut<'_> {
+///         MapMut {
+///             terrain: LayerMut::from(&mut self.terrain),
+///             actors: LayerMut::from(&mut self.actors),
+///         }
+///     }
+/// }
+/// ```
+pub trait Layers {
+    /// Mutable access to the elements of each layer.
+    type Mut<'a>
+    where
+        Self: 'a;
+
+    /// The bounds of each layer.
+    fn layer_bounds(&self) -> Vec<OriginCenteredBounds>;
+
+    /// Apply a transform to each layer.
+    fn transform_layers<F: LayerTransform>(&mut self, transform: &mut F);
+
+    /// Mutable access to the elements, but not the bounds, of each layer.
+    fn layers_mut(&mut self) -> Self::Mut<'_>;
+}
+
+/// Mutable access to the elements of one layer of a [`LayeredGrid`].
+///
+/// Elements can be stored, changed, moved and removed, but the bounds of the layer cannot be
+/// changed, which keeps the layers aligned. The layer dereferences to its [`Grid`] for read-only
+/// access.
+pub struct LayerMut<'a, T> {
+    grid: &'a mut Grid<T>,
+}
+
+impl<T> LayerMut<'_, T> {
+    /// A mutable reference to an element in the layer. See [`Grid::get_mut_element`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    ///
+    /// This method returns an error if the provided position is out of bounds.
+    pub fn get_mut_element<C: Positioned>(&mut self, coordinate: &C) -> Result<&mut T, GridError> {
+        self.grid.get_mut_element(coordinate)
+    }
+
+    /// Store an element at a coordinate. See [`Grid::store_element`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate argument is out of bounds.
+    pub fn store_element<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        element: T,
+    ) -> Result<Option<T>, GridError> {
+        self.grid.store_element(coordinate, element)
+    }
+
+    /// Remove an element, leaving an empty coordinate in its place. See
+    /// [`Grid::remove_element`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate argument is out of bounds.
+    ///
+    /// Returns an error if there is no element at the coordinate.
+    pub fn remove_element<C: Positioned>(&mut self, coordinate: &C) -> Result<T, GridError> {
+        self.grid.remove_element(coordinate)
+    }
+
+    /// Move an element within the layer in a direction. See
+    /// [`Grid::move_element_in_direction`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the resulting move would be out of bounds.
+    ///
+    /// This method returns an error if the resulting move would result in a collision.
+    ///
+    /// This method returns an error if the coordinate argument does not contain an element.
+    pub fn move_element_in_direction(
+        &mut self,
+        coordinate: &Coordinate,
+        direction: AbsoluteDirection,
+    ) -> Result<Coordinate, GridError> {
+        self.grid.move_element_in_direction(coordinate, direction)
+    }
+
+    /// All coordinates of the layer along with mutable references to their elements. See
+    /// [`Grid::iter_mut_new`].
+    pub fn iter_mut_new(&mut self) -> GridIterMut<'_, T> {
+        self.grid.iter_mut_new()
+    }
+
+    /// The occupied coordinates of the layer along with mutable references to their elements.
+    /// See [`Grid::iter_mut_elements_new`].
+    pub fn iter_mut_elements_new(&mut self) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        self.grid.iter_mut_elements_new()
+    }
+}
+
+impl<'a, T> From<&'a mut Grid<T>> for LayerMut<'a, T> {
+    fn from(grid: &'a mut Grid<T>) -> Self {
+        Self { grid }
+    }
+}
+
+impl<T> Deref for LayerMut<'_, T> {
+    type Target = Grid<T>;
+
+    fn deref(&self) -> &Grid<T> {
+        self.grid
+    }
+}
+
+macro_rules! impl_layers_for_tuple {
+    ($($layer:ident: $index:tt),+) => {
+        impl<$($layer),+> Layers for ($(Grid<$layer>,)+) {
+            type Mut<'a> = ($(LayerMut<'a, $layer>,)+) where Self: 'a;
+
+            fn layer_bounds(&self) -> Vec<OriginCenteredBounds> {
+                vec![$(self.$index.bounds()),+]
+            }
+
+            fn transform_layers<Op: LayerTransform>(&mut self, transform: &mut Op) {
+                $(transform.transform(&mut self.$index);)+
+            }
+
+            fn layers_mut(&mut self) -> Self::Mut<'_> {
+                ($(LayerMut::from(&mut self.$index),)+)
+            }
+        }
+    };
+}
+
+impl_layers_for_tuple!(A: 0);
+impl_layers_for_tuple!(A: 0, B: 1);
+impl_layers_for_tuple!(A: 0, B: 1, C: 2);
+impl_layers_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_layers_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_layers_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Several grids with different element types that share one set of bounds.
+///
+/// Each layer is a [`Grid`] of its own, for instance terrain, items and actors. Adding rows and
+/// columns, transposing, rotating and flipping always applies to every layer at once, so that the
+/// element at a coordinate in one layer stays aligned with the elements at the same coordinate in
+/// the other layers. Elements are accessed through [`layers`](LayeredGrid::layers()) and
+/// [`layers_mut`](LayeredGrid::layers_mut()), which cannot change the bounds of a single layer.
+///
+/// # Examples
+/// ```
+/// use tudi::Coordinate;
+/// use tudi::LayeredGrid;
+/// use tudi::grid;
+/// let mut map = LayeredGrid::new((grid!(3, 2), grid!(3, 2))).unwrap();
+/// let (mut terrain, mut actors) = map.layers_mut();
+/// terrain.store_element(&Coordinate { x: 1, y: 1 }, '#').unwrap();
+/// actors.store_element(&Coordinate { x: 1, y: 1 }, "troll").unwrap();
+///
+/// map.rotate_clockwise();
+/// let (terrain, actors) = map.layers();
+/// assert_eq!(terrain.element(&Coordinate { x: 1, y: -1 }), Ok(&'#'));
+/// assert_eq!(actors.element(&Coordinate { x: 1, y: -1 }), Ok(&"troll"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayeredGrid<L> {
+    layers: L,
+    bounds: OriginCenteredBounds,
+}
+
+impl<L: Layers> LayeredGrid<L> {
+    /// Combine grids with the same bounds into layers.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`GridError::ShapeMismatchError`] if the layers have different
+    /// bounds or if there are no layers.
+    pub fn new(layers: L) -> Result<Self, GridError> {
+        let bounds = shared_bounds(&layers).ok_or(GridError::ShapeMismatchError)?;
+        Ok(Self { layers, bounds })
+    }
+
+    /// The bounds that the layers share.
+    pub fn bounds(&self) -> OriginCenteredBounds {
+        self.bounds
+    }
+
+    /// The layers.
+    pub fn layers(&self) -> &L {
+        &self.layers
+    }
+
+    /// Mutable access to the elements of the layers.
+    pub fn layers_mut(&mut self) -> L::Mut<'_> {
+        self.layers.layers_mut()
+    }
+
+    /// The grids that make up the layers.
+    pub fn into_layers(self) -> L {
+        self.layers
+    }
+
+    /// Apply an operation to every layer.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the operation leaves the layers with different bounds, for instance
+    /// because the new bounds depend on the elements of each layer.
+    pub fn transform<F: LayerTransform>(&mut self, transform: &mut F) {
+        self.layers.transform_layers(transform);
+        self.bounds = shared_bounds(&self.layers)
+            .expect("the transform changes the bounds of every layer in the same way");
+    }
+
+    /// Add an empty row to every layer. See [`Grid::add_row`].
+    ///
+    /// Returns true if the row was added to the north.
+    pub fn add_row(&mut self) -> bool {
+        let y_max = self.y_max_boundary();
+        self.transform(&mut Reshape::AddRow);
+        self.y_max_boundary() != y_max
+    }
+
+    /// Add an empty column to every layer. See [`Grid::add_column`].
+    ///
+    /// Returns true if the column was added to the east.
+    pub fn add_column(&mut self) -> bool {
+        let x_max = self.x_max_boundary();
+        self.transform(&mut Reshape::AddColumn);
+        self.x_max_boundary() != x_max
+    }
+
+    /// Expand every layer at a row. See [`Grid::expand_at_row`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided y-coordinate is out of bounds. No layer is
+    /// changed in that case.
+    pub fn expand_at_row(&mut self, y_coord: i32) -> Result<VerticalDirection, GridError> {
+        self.coordinate_to_index(&Coordinate { x: 0, y: y_coord })?;
+        if self.add_row() {
+            self.transform(&mut Reshape::MoveRowsNorth(y_coord));
+            Ok(VerticalDirection::North)
+        } else {
+            self.transform(&mut Reshape::MoveRowsSouth(y_coord));
+            Ok(VerticalDirection::South)
+        }
+    }
+
+    /// Expand every layer at a column. See [`Grid::expand_at_column`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided x-coordinate is out of bounds. No layer is
+    /// changed in that case.
+    pub fn expand_at_column(&mut self, x_coord: i32) -> Result<HorizontalDirection, GridError> {
+        self.coordinate_to_index(&Coordinate { x: x_coord, y: 0 })?;
+        if self.add_column() {
+            self.transform(&mut Reshape::MoveColumnsEast(x_coord));
+            Ok(HorizontalDirection::East)
+        } else {
+            self.transform(&mut Reshape::MoveColumnsWest(x_coord));
+            Ok(HorizontalDirection::West)
+        }
+    }
+
+    /// Transpose every layer. See [`Grid::transpose_new`].
+    pub fn transpose_new(&mut self) {
+        self.transform(&mut Reshape::Transpose);
+    }
+
+    /// Transpose every layer along the anti-diagonal. See [`Grid::transpose_anti_diagonal`].
+    pub fn transpose_anti_diagonal(&mut self) {
+        self.transform(&mut Reshape::TransposeAntiDiagonal);
+    }
+
+    /// Rotate every layer clockwise. See [`Grid::rotate_clockwise`].
+    pub fn rotate_clockwise(&mut self) {
+        self.transform(&mut Reshape::RotateClockwise);
+    }
+
+    /// Rotate every layer counterclockwise. See [`Grid::rotate_counterclockwise`].
+    pub fn rotate_counterclockwise(&mut self) {
+        self.transform(&mut Reshape::RotateCounterclockwise);
+    }
+
+    /// Rotate every layer by 180 degrees. See [`Grid::rotate_180`].
+    pub fn rotate_180(&mut self) {
+        self.transform(&mut Reshape::Rotate180);
+    }
+
+    /// Flip every layer horizontally. See [`Grid::flip_horizontal`].
+    pub fn flip_horizontal(&mut self) {
+        self.transform(&mut Reshape::FlipHorizontal);
+    }
+
+    /// Flip every layer vertically. See [`Grid::flip_vertical`].
+    pub fn flip_vertical(&mut self) {
+        self.transform(&mut Reshape::FlipVertical);
+    }
+}
+
+/// The bounds of the layers, or `None` if there are no layers or their bounds differ.
+fn shared_bounds<L: Layers>(layers: &L) -> Option<OriginCenteredBounds> {
+    let bounds = layers.layer_bounds();
+    let first = *bounds.first()?;
+    bounds
+        .iter()
+        .all(|bounds| *bounds == first)
+        .then_some(first)
+}
+
+/// The operations that a [`LayeredGrid`] applies to its layers.
+enum Reshape {
+    AddRow,
+    AddColumn,
+    /// The second half of [`Grid::expand_at_row`] after a row was added to the north.
+    MoveRowsNorth(i32),
+    /// The second half of [`Grid::expand_at_row`] after a row was added to the south.
+    MoveRowsSouth(i32),
+    /// The second half of [`Grid::expand_at_column`] after a column was added to the east.
+    MoveColumnsEast(i32),
+    /// The second half of [`Grid::expand_at_column`] after a column was added to the west.
+    MoveColumnsWest(i32),
+    Transpose,
+    TransposeAntiDiagonal,
+    RotateClockwise,
+    RotateCounterclockwise,
+    Rotate180,
+    FlipHorizontal,
+    FlipVertical,
+}
+
+impl LayerTransform for Reshape {
+    fn transform<T>(&mut self, layer: &mut Grid<T>) {
+        match *self {
+            Reshape::AddRow => {
+                layer.add_row();
+            }
+            Reshape::AddColumn => {
+                layer.add_column();
+            }
+            Reshape::MoveRowsNorth(y_coord) => layer
+                .move_elements_above_row_in_direction(y_coord, AbsoluteDirection::North)
+                .expect("the added row provides space to move elements north"),
+            Reshape::MoveRowsSouth(y_coord) => layer
+                .move_elements_below_row_in_direction(y_coord, AbsoluteDirection::South)
+                .expect("the added row provides space to move elements south"),
+            Reshape::MoveColumnsEast(x_coord) => layer
+                .move_elements_right_of_column_in_direction(x_coord, AbsoluteDirection::East)
+                .expect("the added column provides space to move elements east"),
+            Reshape::MoveColumnsWest(x_coord) => layer
+                .move_elements_left_of_column_in_direction(x_coord, AbsoluteDirection::West)
+                .expect("the added column provides space to move elements west"),
+            Reshape::Transpose => layer.transpose_new(),
+            Reshape::TransposeAntiDiagonal => layer.transpose_anti_diagonal(),
+            Reshape::RotateClockwise => layer.rotate_clockwise(),
+            Reshape::RotateCounterclockwise => layer.rotate_counterclockwise(),
+            Reshape::Rotate180 => layer.rotate_180(),
+            Reshape::FlipHorizontal => layer.flip_horizontal(),
+            Reshape::FlipVertical => layer.flip_vertical(),
+        }
+    }
+}
+
+impl<L> OriginCenteredness for LayeredGrid<L> {
+    type Distinguisher = OriginCentered;
+}
+
+impl<L> OriginBounded for LayeredGrid<L> {
+    fn x_count(&self) -> AxisCount {
+        self.bounds.x_count()
+    }
+
+    fn y_count(&self) -> AxisCount {
+        self.bounds.y_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    /// A 3x2 map with terrain and actor layers.
+    fn map() -> LayeredGrid<(Grid<char>, Grid<u32>)> {
+        let mut map = LayeredGrid::new((grid!(3, 2), grid!(3, 2))).unwrap();
+        let (mut terrain, mut actors) = map.layers_mut();
+        terrain
+            .store_element(&Coordinate { x: -1, y: 1 }, '#')
+            .unwrap();
+        terrain
+            .store_element(&Coordinate { x: 1, y: 0 }, '~')
+            .unwrap();
+        actors.store_element(&Coordinate { x: 1, y: 0 }, 7).unwrap();
+        map
+    }
+
+    /// The coordinates of the elements in each layer.
+    fn layer_statuses(map: &LayeredGrid<(Grid<char>, Grid<u32>)>) -> [String; 2] {
+        let (terrain, actors) = map.layers();
+        [terrain.element_statuses(), actors.element_statuses()]
+    }
+
+    #[test]
+    fn layers_must_share_bounds() {
+        let layers: (Grid<()>, Grid<u8>) = (grid!(3, 2), Grid::default());
+        assert_eq!(
+            LayeredGrid::new(layers).map(|_| ()),
+            Err(GridError::ShapeMismatchError)
+        );
+        let layers: (Grid<()>, Grid<u8>, Grid<bool>) = (grid!(3, 2), grid!(3, 2), grid!(3, 2));
+        assert!(LayeredGrid::new(layers).is_ok());
+    }
+
+    #[test]
+    fn transforms_apply_to_every_layer() {
+        let mut map = map();
+        map.transpose_new();
+        assert_eq!(layer_statuses(&map), ["#.\n..\n.#", "..\n..\n.#"]);
+        assert_eq!(map.layers().1.element(&Coordinate { x: 1, y: -1 }), Ok(&7));
+
+        map.rotate_clockwise();
+        map.flip_vertical();
+        map.rotate_180();
+        map.flip_horizontal();
+        map.rotate_counterclockwise();
+        map.transpose_anti_diagonal();
+        let (terrain, actors) = map.layers();
+        let tilde = terrain
+            .iter_elements_new()
+            .find(|(_, element)| **element == '~')
+            .map(|(coordinate, _)| coordinate);
+        assert_eq!(
+            tilde,
+            actors
+                .iter_elements_new()
+                .next()
+                .map(|(coordinate, _)| coordinate)
+        );
+        assert_eq!(map.bounds(), terrain.bounds());
+    }
+
+    #[test]
+    fn expansion_applies_to_every_layer() {
+        let mut map = map();
+        let mut expected = map.clone().into_layers();
+        assert_eq!(map.expand_at_column(1), expected.0.expand_at_column(1));
+        expected.1.expand_at_column(1).unwrap();
+        assert_eq!(map.expand_at_row(0), expected.0.expand_at_row(0));
+        expected.1.expand_at_row(0).unwrap();
+        assert!(map.add_row());
+        expected.0.add_row();
+        expected.1.add_row();
+        assert!(!map.add_column());
+        expected.0.add_column();
+        expected.1.add_column();
+
+        assert_eq!(map.layers(), &expected);
+        assert_eq!(OriginBounded::x_count(&map), 5);
+        assert_eq!(OriginBounded::y_count(&map), 4);
+    }
+
+    #[test]
+    fn failed_expansion_changes_no_layer() {
+        let mut map = map();
+        assert!(map.expand_at_row(2).is_err());
+        assert!(map.expand_at_column(-2).is_err());
+        assert_eq!(layer_statuses(&map), ["#..\n..#", "...\n..#"]);
+        assert_eq!(OriginBounded::x_count(&map), 3);
+    }
+
+    /// A transform that depends on the elements of each layer.
+    struct AddRowIfOccupied;
+
+    impl LayerTransform for AddRowIfOccupied {
+        fn transform<T>(&mut self, layer: &mut Grid<T>) {
+            if layer.iter_elements_new().count() > 1 {
+                layer.add_row();
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the transform changes the bounds of every layer in the same way")]
+    fn misaligning_transforms_panic() {
+        map().transform(&mut AddRowIfOccupied);
+    }
+}
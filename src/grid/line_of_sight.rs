@@ -0,0 +1,214 @@
+use super::Grid;
+use crate::Coordinate;
+use crate::OutOfBoundsError;
+use crate::Positioned;
+use crate::bounded::Bounded;
+
+impl<T> Grid<T> {
+    /// The coordinates on the straight line from one coordinate to another, along with their
+    /// elements.
+    ///
+    /// The line is clipped to the grid: coordinates of the line that are out of bounds are left
+    /// out, and are skipped without being visited. See [`Line`](crate::Line) for how the line is
+    /// drawn.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let grid: tudi::Grid<()> = grid!(5, 5);
+    /// let line = grid.line(Coordinate { x: -4, y: 0 }, Coordinate { x: 4, y: 0 });
+    /// assert_eq!(line.count(), 5);
+    /// ```
+    pub fn line(
+        &self,
+        from: Coordinate,
+        to: Coordinate,
+    ) -> impl Iterator<Item = (Coordinate, Option<&T>)> + '_ {
+        from.line_to(&to)
+            .clip(self)
+            .map(|coordinate| (coordinate, self.element_unchecked(&coordinate)))
+    }
+
+    /// The first element on the straight line from one coordinate to another that blocks the
+    /// line, or `None` if the line is clear.
+    ///
+    /// The element at `from` is not considered, while the element at `to` is. If `to` is out of
+    /// bounds, the line is followed until it leaves the grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if `from` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let mut grid = grid!(5, 5);
+    /// grid.store_element(&Coordinate { x: 1, y: 1 }, "wall");
+    /// grid.store_element(&Coordinate { x: -1, y: 1 }, "glass");
+    ///
+    /// let blocks = |_, element: &&str| *element == "wall";
+    /// let origin = Coordinate::default();
+    /// assert_eq!(
+    ///     grid.line_of_sight(origin, Coordinate { x: 2, y: 2 }, blocks),
+    ///     Ok(Some((Coordinate { x: 1, y: 1 }, &"wall")))
+    /// );
+    /// assert_eq!(grid.line_of_sight(origin, Coordinate { x: -2, y: 2 }, blocks), Ok(None));
+    /// ```
+    pub fn line_of_sight(
+        &self,
+        from: Coordinate,
+        to: Coordinate,
+        mut blocks: impl FnMut(Coordinate, &T) -> bool,
+    ) -> Result<Option<(Coordinate, &T)>, OutOfBoundsError> {
+        self.coordinate_to_index(&from)?;
+        Ok(self
+            .line(from, to)
+            .skip(1)
+            .find_map(|(coordinate, element)| {
+                element
+                    .filter(|element| blocks(coordinate, element))
+                    .map(|element| (coordinate, element))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbsoluteDirection;
+    use crate::grid;
+
+    fn walled_grid() -> Grid<u8> {
+        let mut grid = grid!(7, 7);
+        for y in -3..=3 {
+            grid.store_element(&Coordinate { x: 2, y }, 1).unwrap();
+        }
+        grid.store_element(&Coordinate { x: 1, y: 0 }, 0).unwrap();
+        grid
+    }
+
+    #[test]
+    fn line_is_clipped_to_the_grid() {
+        let grid = walled_grid();
+        let line = grid
+            .line(Coordinate { x: -5, y: -5 }, Coordinate { x: 5, y: 5 })
+            .map(|(coordinate, _)| coordinate)
+            .collect::<Vec<_>>();
+        assert_eq!(line.len(), 7);
+        assert_eq!(line.first(), Some(&Coordinate { x: -3, y: -3 }));
+        assert_eq!(line.last(), Some(&Coordinate { x: 3, y: 3 }));
+
+        assert_eq!(
+            grid.line(Coordinate { x: 4, y: 0 }, Coordinate { x: 9, y: 4 })
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn far_away_endpoints_are_clipped_without_walking_the_line() {
+        let grid: Grid<()> = grid!(3, 3);
+        let line = grid
+            .line(
+                Coordinate {
+                    x: i32::MIN + 1,
+                    y: i32::MIN + 1,
+                },
+                Coordinate {
+                    x: i32::MAX,
+                    y: i32::MAX,
+                },
+            )
+            .map(|(coordinate, _)| coordinate)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            line,
+            [(-1, -1), (0, 0), (1, 1)].map(|(x, y)| Coordinate { x, y })
+        );
+
+        assert_eq!(
+            grid.line(
+                Coordinate { x: i32::MAX, y: 0 },
+                Coordinate { x: i32::MIN, y: 5 }
+            )
+            .count(),
+            0
+        );
+
+        let blocks = |_, _: &()| true;
+        assert_eq!(
+            grid.line_of_sight(
+                Coordinate::default(),
+                Coordinate { x: i32::MAX, y: 7 },
+                blocks
+            ),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn line_yields_elements() {
+        let grid = walled_grid();
+        let elements = grid
+            .line(Coordinate::default(), Coordinate { x: 3, y: 0 })
+            .map(|(_, element)| element.copied())
+            .collect::<Vec<_>>();
+        assert_eq!(elements, [None, Some(0), Some(1), None]);
+    }
+
+    #[test]
+    fn first_blocking_element() {
+        let grid = walled_grid();
+        let blocks = |_, element: &u8| *element > 0;
+        assert_eq!(
+            grid.line_of_sight(Coordinate::default(), Coordinate { x: 9, y: 0 }, blocks),
+            Ok(Some((Coordinate { x: 2, y: 0 }, &1)))
+        );
+        assert_eq!(
+            grid.line_of_sight(
+                Coordinate { x: 3, y: 0 },
+                Coordinate { x: -3, y: 3 },
+                blocks
+            ),
+            Ok(Some((Coordinate { x: 2, y: 1 }, &1)))
+        );
+        assert_eq!(
+            grid.line_of_sight(Coordinate::default(), Coordinate { x: 1, y: 0 }, blocks),
+            Ok(None)
+        );
+        assert_eq!(
+            grid.line_of_sight(Coordinate::default(), Coordinate { x: -9, y: 9 }, blocks),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn line_of_sight_ignores_the_start() {
+        let grid = walled_grid();
+        assert_eq!(
+            grid.line_of_sight(
+                Coordinate { x: 2, y: 0 },
+                Coordinate { x: 3, y: 0 },
+                |_, _| true
+            ),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn line_of_sight_from_out_of_bounds() {
+        let grid = walled_grid();
+        assert_eq!(
+            grid.line_of_sight(Coordinate { x: 0, y: -4 }, Coordinate::default(), |_, _| {
+                true
+            }),
+            Err(OutOfBoundsError::new(
+                Coordinate { x: 0, y: -4 },
+                AbsoluteDirection::South,
+                None
+            ))
+        );
+    }
+}
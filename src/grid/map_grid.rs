@@ -0,0 +1,265 @@
+use super::Grid;
+use super::grid_storage::GridStorage;
+use super::grid_storage::RowMajorKey;
+use crate::Coordinate;
+use crate::GridError;
+use itertools::EitherOrBoth;
+use itertools::Itertools;
+use std::convert::Infallible;
+
+impl<T> Grid<T> {
+    /// Convert each element of the grid, keeping the bounds and the occupied coordinates.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::Grid;
+    /// let grid: Grid<char> = [(Coordinate { x: 0, y: 0 }, '#')].into_iter().collect();
+    ///
+    /// let grid = grid.map(|element| element == '#');
+    /// assert_eq!(grid.element(&Coordinate { x: 0, y: 0 }), Ok(&true));
+    /// ```
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Grid<U> {
+        self.map_with_coordinate(|_, element| f(element))
+    }
+
+    /// Convert each element of the grid along with its coordinate, keeping the bounds and the
+    /// occupied coordinates.
+    pub fn map_with_coordinate<U>(self, mut f: impl FnMut(Coordinate, T) -> U) -> Grid<U> {
+        let Ok(grid) = self.try_map_with_coordinate(|coordinate, element| {
+            Ok::<_, Infallible>(f(coordinate, element))
+        });
+        grid
+    }
+
+    /// Convert each element of the grid, removing the elements for which the function returns
+    /// `None`. The bounds are kept.
+    pub fn filter_map<U>(self, mut f: impl FnMut(T) -> Option<U>) -> Grid<U> {
+        let Ok(storage) = self
+            .storage
+            .try_filter_map(&self.bounds, |_, element| Ok::<_, Infallible>(f(element)));
+
+        let mut grid = Grid {
+            storage,
+            bounds: self.bounds,
+            performance_tuning: self.performance_tuning,
+            wrap_mode: self.wrap_mode,
+            speed_cache: None,
+        };
+        grid.apply_performance_tuning();
+        grid
+    }
+
+    /// Convert each element of the grid, keeping the bounds and the occupied coordinates.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the first error returned by the function, in the order of
+    /// [`iter_elements_new`](Grid::iter_elements_new()). The function is not called for the
+    /// remaining elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::Grid;
+    /// let grid: Grid<char> = [(Coordinate { x: 0, y: 0 }, '7'), (Coordinate { x: 1, y: 0 }, 'x')]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let digits = grid.try_map(|element| element.to_digit(10).ok_or(element));
+    /// assert_eq!(digits.unwrap_err(), 'x');
+    /// ```
+    pub fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<Grid<U>, E> {
+        self.try_map_with_coordinate(|_, element| f(element))
+    }
+
+    /// Combine the elements of two grids with the same bounds into a new grid.
+    ///
+    /// The function is called with the elements of both grids at each coordinate where at least
+    /// one of them has an element, and the result is stored at that coordinate. Coordinates that
+    /// are empty in both grids stay empty. The new grid uses the
+    /// [`PerformanceTuning`](crate::PerformanceTuning) of this grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`GridError::ShapeMismatchError`] if the bounds of the grids differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let mut walls = grid!(3, 3);
+    /// let mut visited = grid!(3, 3);
+    /// walls.store_element(&Coordinate { x: -1, y: 0 }, ());
+    /// visited.store_element(&Coordinate { x: 1, y: 0 }, ());
+    ///
+    /// let blocked = walls
+    ///     .zip_with(&visited, |wall, visited| (wall.is_some() || visited.is_some()).then_some(()))
+    ///     .unwrap();
+    /// assert_eq!(blocked.element_statuses(), "...\n#.#\n...");
+    /// ```
+    pub fn zip_with<U, V>(
+        &self,
+        other: &Grid<U>,
+        mut f: impl FnMut(Option<&T>, Option<&U>) -> Option<V>,
+    ) -> Result<Grid<V>, GridError> {
+        if self.bounds != other.bounds {
+            return Err(GridError::ShapeMismatchError);
+        }
+
+        let elements = self
+            .iter_elements_new()
+            .merge_join_by(other.iter_elements_new(), |(left, _), (right, _)| {
+                RowMajorKey::new(*left).cmp(&RowMajorKey::new(*right))
+            })
+            .filter_map(|pair| {
+                let element = match pair {
+                    EitherOrBoth::Both((coordinate, left), (_, right)) => {
+                        (coordinate, f(Some(left), Some(right)))
+                    }
+                    EitherOrBoth::Left((coordinate, left)) => (coordinate, f(Some(left), None)),
+                    EitherOrBoth::Right((coordinate, right)) => (coordinate, f(None, Some(right))),
+                };
+                Some((RowMajorKey::new(element.0), element.1?))
+            })
+            .collect();
+
+        let mut grid = Grid {
+            storage: GridStorage::Sparse(elements),
+            bounds: self.bounds,
+            performance_tuning: self.performance_tuning,
+            wrap_mode: self.wrap_mode,
+            speed_cache: None,
+        };
+        grid.apply_performance_tuning();
+        Ok(grid)
+    }
+
+    /// Convert each element along with its coordinate. The occupancy does not change, so the
+    /// speed cache stays valid.
+    fn try_map_with_coordinate<U, E>(
+        self,
+        mut f: impl FnMut(Coordinate, T) -> Result<U, E>,
+    ) -> Result<Grid<U>, E> {
+        let storage = self
+            .storage
+            .try_filter_map(&self.bounds, |coordinate, element| {
+                f(coordinate, element).map(Some)
+            })?;
+
+        Ok(Grid {
+            storage,
+            bounds: self.bounds,
+            performance_tuning: self.performance_tuning,
+            wrap_mode: self.wrap_mode,
+            speed_cache: self.speed_cache,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PerformanceTuning;
+    use crate::grid::generic_grid::tests::TUNINGS;
+    use crate::grid::generic_grid::tests::checkered_grid;
+
+    #[test]
+    fn map_keeps_shape_and_tuning() {
+        for tuning in TUNINGS {
+            let grid = checkered_grid(5, 4, tuning);
+            let expected = grid
+                .iter_elements_new()
+                .map(|(coordinate, element)| (coordinate, element.to_string()))
+                .collect::<Vec<_>>();
+
+            let mapped = grid.map(|element| element.to_string());
+            assert_eq!(mapped.performance_tuning(), tuning);
+            assert_eq!(mapped.bounds(), checkered_grid(5, 4, tuning).bounds());
+            assert_eq!(
+                mapped
+                    .iter_elements_new()
+                    .map(|(coordinate, element)| (coordinate, element.clone()))
+                    .collect::<Vec<_>>(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn map_with_coordinate_passes_the_coordinate() {
+        for tuning in TUNINGS {
+            let grid = checkered_grid(5, 4, tuning)
+                .map_with_coordinate(|coordinate, element| element - 10 * coordinate.x);
+            assert!(
+                grid.iter_elements_new()
+                    .all(|(coordinate, element)| *element == coordinate.y)
+            );
+        }
+    }
+
+    #[test]
+    fn filter_map_removes_elements() {
+        for tuning in TUNINGS {
+            let grid =
+                checkered_grid(5, 4, tuning).filter_map(|element| (element > 0).then_some(element));
+            assert_eq!(grid.element_statuses(), "..#.#\n...#.\n....#\n...#.");
+            assert_eq!(grid.iter_elements_new().count(), 5);
+            assert_eq!(grid.empty_columns(), vec![-2, -1]);
+        }
+    }
+
+    #[test]
+    fn try_map_stops_at_the_first_error() {
+        for tuning in TUNINGS {
+            let mut calls = 0;
+            let result = checkered_grid(5, 4, tuning).try_map(|element| {
+                calls += 1;
+                if element < 0 {
+                    Err(element)
+                } else {
+                    Ok(element)
+                }
+            });
+            // The first element from west to east, north to south is at (-2, 2).
+            assert_eq!(result.unwrap_err(), -18);
+            assert_eq!(calls, 1);
+
+            let grid = checkered_grid(5, 4, tuning).try_map(Ok::<_, ()>).unwrap();
+            assert_eq!(grid, checkered_grid(5, 4, tuning));
+        }
+    }
+
+    #[test]
+    fn zip_with_combines_elements() {
+        for tuning in TUNINGS {
+            let left =
+                checkered_grid(5, 4, tuning).filter_map(|element| (element > 0).then_some(element));
+            let right = checkered_grid(5, 4, PerformanceTuning::Auto)
+                .filter_map(|element| (element < 10).then_some(()));
+
+            let zipped = left
+                .zip_with(&right, |left, right| match (left, right) {
+                    (Some(left), Some(())) => Some(*left),
+                    (Some(_), None) => None,
+                    (None, _) => Some(0),
+                })
+                .unwrap();
+            assert_eq!(zipped.performance_tuning(), tuning);
+            assert_eq!(zipped.element_statuses(), "#.#..\n.#...\n#.#..\n.#.#.");
+            assert_eq!(zipped.element(&Coordinate { x: 0, y: 2 }), Ok(&2));
+            assert_eq!(zipped.element(&Coordinate { x: -2, y: 0 }), Ok(&0));
+        }
+    }
+
+    #[test]
+    fn zip_with_requires_equal_bounds() {
+        let left = checkered_grid(5, 4, PerformanceTuning::Auto);
+        let mut right = left.clone();
+        right.transpose_new();
+        assert_eq!(
+            left.zip_with(&right, |_, _| Some(())).unwrap_err(),
+            GridError::ShapeMismatchError
+        );
+    }
+}
@@ -1,15 +1,50 @@
+mod automaton;
 mod clone_grid;
+mod field_of_view;
+mod flood_fill;
 mod generic_grid;
 mod grid_coordinate;
 mod grid_creation_error;
 mod grid_error;
 mod grid_iter;
+mod grid_storage;
+mod grid_view;
+mod growing_grid;
+mod layered_grid;
+mod line_of_sight;
+mod map_grid;
+mod offset_grid;
 mod performance_tuning;
+mod region_iter;
+mod screen_access;
+mod simulation;
+mod speed_cache;
+mod stack_grid;
 use crate::OriginCenteredBounds;
-use grid_coordinate::GridCoordinate;
+use crate::WrapMode;
+pub use automaton::LifeRule;
+pub use automaton::LifeRuleError;
+pub use automaton::Rule;
+pub use flood_fill::Component;
 pub use grid_creation_error::GridCreationError;
 pub use grid_error::GridError;
-use performance_tuning::PerformanceTuning;
+pub use grid_iter::GridIntoIter;
+pub use grid_iter::GridIter;
+pub use grid_iter::GridIterMut;
+use grid_storage::GridStorage;
+pub use grid_view::GridView;
+pub use grid_view::GridViewMut;
+pub use growing_grid::GrowingGrid;
+pub use layered_grid::LayerMut;
+pub use layered_grid::LayerTransform;
+pub use layered_grid::LayeredGrid;
+pub use layered_grid::Layers;
+pub use offset_grid::OffsetGrid;
+pub use performance_tuning::PerformanceTuning;
+pub use simulation::Cycle;
+pub use simulation::Simulation;
+use speed_cache::SpeedCache;
+pub use stack_grid::StackGrid;
 
 /// A bounded two-dimensional grid that either contains an element of type T or is empty at each
 /// point.
@@ -31,11 +66,22 @@ use performance_tuning::PerformanceTuning;
 ///
 /// - A grid is either empty or contains a single element T at each coordinate.
 ///
+/// - A grid's borders are walls by default, but a grid can also wrap around like a torus. See [`WrapMode`].
+///
 /// ### Capacity
 ///
-/// The maximum size of a grid is currently constrained by memory allocation for the elements, since it internally creates a vec
-/// to hold the elements. The maximum capacity is therefore roughly `x_count * y_count * max(size_of::<T> (),
-/// size_of::<Coordinate>() <= isize::MAX`. This is inefficient for sparse grids and might change in the future.
+/// A grid either stores its elements densely, with one entry per coordinate, or sparsely, with one
+/// entry per occupied coordinate. The representation is chosen by the grid's [`PerformanceTuning`]
+/// and does not affect the behavior of any grid method.
+///
+/// The maximum size of a densely stored grid is constrained by memory allocation for the elements,
+/// since it internally creates a vec to hold the elements. The maximum capacity is therefore roughly
+/// `x_count * y_count * max(size_of::<T> (), size_of::<Coordinate>() <= isize::MAX`. A sparsely
+/// stored grid only allocates memory for its elements, so its coordinate count is only constrained
+/// by [`AxisCount::MAX`](crate::AxisCount::MAX) along each axis.
+///
+/// By default, a grid uses [`PerformanceTuning::Auto`], which stores small or densely populated
+/// grids densely and large, sparsely populated grids sparsely.
 ///
 /// # Examples
 ///
@@ -55,11 +101,13 @@ use performance_tuning::PerformanceTuning;
 /// assert!(grid.element(&Coordinate::default()).is_ok()); // the grid now contains an element at
 /// // the origin.
 /// ```
-#[derive(Debug)]
+#[derive(Clone)]
 pub struct Grid<T> {
-    grid_data: Vec<GridCoordinate<T>>,
+    storage: GridStorage<T>,
     bounds: OriginCenteredBounds,
     performance_tuning: PerformanceTuning,
+    wrap_mode: WrapMode,
+    speed_cache: Option<SpeedCache>,
 }
 
 /// Creates an empty [`Grid`] from literal axis counts.
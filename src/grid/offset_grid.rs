@@ -0,0 +1,659 @@
+use super::Grid;
+use crate::AbsoluteDirection;
+use crate::BoundedMovingObject;
+use crate::Bounds;
+use crate::Coordinate;
+use crate::GridError;
+use crate::OutOfBoundsError;
+use crate::Positioned;
+use crate::WrapMode;
+use crate::bounded::Bounded;
+use crate::bounded::MaybeOriginBounded;
+use crate::bounded::MaybeOriginCentered;
+use crate::bounded::OriginBounded;
+use crate::bounded::OriginCenteredness;
+use std::fmt;
+
+/// A grid with arbitrary bounds.
+///
+/// A [`Grid`] is always centered around the origin. An offset grid stores its elements in a
+/// [`Grid`] with the same counts, but uses the coordinates of its own bounds, for instance
+/// `0..width` and `0..height`. Coordinates are translated between the two whenever the grid is
+/// accessed.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use tudi::Bounds;
+/// use tudi::Coordinate;
+/// use tudi::Grid;
+/// use tudi::OffsetGrid;
+/// let walls = HashMap::from([('#', ())]);
+/// let grid = Grid::from_str_by_map("#..\n...\n..#", &walls).unwrap();
+///
+/// // Put the southwest corner of the map at the origin.
+/// let map = OffsetGrid::from_grid(grid, &Bounds::from_boundaries(0, 2, 0, 2)).unwrap();
+/// assert!(map.element(&Coordinate { x: 0, y: 2 }).is_ok());
+/// assert!(map.element(&Coordinate { x: 2, y: 0 }).is_ok());
+/// assert!(map.element(&Coordinate { x: -1, y: 1 }).is_err());
+///
+/// let grid = map.into_grid();
+/// assert!(grid.element(&Coordinate { x: -1, y: 1 }).is_ok());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OffsetGrid<T> {
+    grid: Grid<T>,
+    bounds: Bounds,
+}
+
+impl<T> OffsetGrid<T> {
+    /// An empty grid with the bounds of a region.
+    pub fn from_bounds<B: Bounded>(bounds: &B) -> Self {
+        Self {
+            grid: Grid::with_count(bounds.x_count(), bounds.y_count()),
+            bounds: Bounds::from_boundaries(
+                bounds.x_min_boundary(),
+                bounds.x_max_boundary(),
+                bounds.y_min_boundary(),
+                bounds.y_max_boundary(),
+            ),
+        }
+    }
+
+    /// Move the elements of a grid to other bounds with the same counts. The element at the
+    /// southwest corner of the grid ends up at the southwest corner of the bounds, and so on.
+    ///
+    /// The grid keeps its [`PerformanceTuning`](crate::PerformanceTuning) and
+    /// [`WrapMode`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`GridError::ShapeMismatchError`] if the counts of the bounds differ
+    /// from the counts of the grid.
+    pub fn from_grid<B: Bounded>(grid: Grid<T>, bounds: &B) -> Result<Self, GridError> {
+        if bounds.x_count() != OriginBounded::x_count(&grid)
+            || bounds.y_count() != OriginBounded::y_count(&grid)
+        {
+            return Err(GridError::ShapeMismatchError);
+        }
+
+        Ok(Self {
+            grid,
+            ..Self::from_bounds(bounds)
+        })
+    }
+
+    /// The origin-centered grid that stores the elements. The element at the southwest corner of
+    /// this grid is at the southwest corner of the returned grid, and so on.
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// The bounds of the grid.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// A reference to an element in the grid.
+    ///
+    /// See also [`Grid::element_unchecked`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the coordinate is out of bounds and the grid is not toroidal.
+    pub fn element_unchecked<C: Positioned>(&self, coordinate: &C) -> Option<&T> {
+        let coordinate = self
+            .grid_coordinate(coordinate)
+            .expect("the coordinate is within bounds");
+        self.grid.element_unchecked(&coordinate)
+    }
+
+    /// A reference to an element in the grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds.
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    pub fn element<C: Positioned>(&self, coordinate: &C) -> Result<&T, GridError> {
+        let grid_coordinate = self.grid_coordinate(coordinate)?;
+        self.grid
+            .element_unchecked(&grid_coordinate)
+            .ok_or(GridError::UnoccupiedError(*coordinate.position()))
+    }
+
+    /// A mutable reference to an element in the grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided position does not contain an element.
+    ///
+    /// This method returns an error if the provided position is out of bounds.
+    pub fn get_mut_element<C: Positioned>(&mut self, coordinate: &C) -> Result<&mut T, GridError> {
+        let grid_coordinate = self.grid_coordinate(coordinate)?;
+        self.grid
+            .get_mut_element(&grid_coordinate)
+            .map_err(|_| GridError::UnoccupiedError(*coordinate.position()))
+    }
+
+    /// Store an element at a coordinate.
+    ///
+    /// If the coordinate was previously empty, `Ok(None)` is returned. If it contained an
+    /// element, the element is replaced and `Ok(Some(old element))` is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate argument is out of bounds.
+    pub fn store_element<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        element: T,
+    ) -> Result<Option<T>, GridError> {
+        let grid_coordinate = self.grid_coordinate(coordinate)?;
+        self.grid.store_element(&grid_coordinate, element)
+    }
+
+    /// Remove an element, leaving an empty coordinate in its place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate argument is out of bounds.
+    ///
+    /// Returns an error if there is no element at the coordinate.
+    pub fn remove_element<C: Positioned>(&mut self, coordinate: &C) -> Result<T, GridError> {
+        let grid_coordinate = self.grid_coordinate(coordinate)?;
+        self.grid
+            .remove_element(&grid_coordinate)
+            .map_err(|_| GridError::UnoccupiedError(*coordinate.position()))
+    }
+
+    /// Move an element within the grid in a direction. See [`Grid::move_element_in_direction`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the resulting move would be out of bounds.
+    ///
+    /// This method returns an error if the resulting move would result in a collision.
+    ///
+    /// This method returns an error if the coordinate argument does not contain an element.
+    pub fn move_element_in_direction(
+        &mut self,
+        coordinate: &Coordinate,
+        direction: AbsoluteDirection,
+    ) -> Result<Coordinate, GridError> {
+        let coordinate = self.resolve(coordinate);
+        let mut marker = BoundedMovingObject::try_from((&self, coordinate))?;
+
+        if marker.move_in_absolute_direction_with(direction, 1, self.grid.wrap_mode()) {
+            if self.element_unchecked(marker.position()).is_some() {
+                return Err(GridError::CollisionError);
+            }
+
+            let grid_coordinate = self.grid_coordinate(&coordinate)?;
+            let grid_target = self.grid_coordinate(marker.position())?;
+            let element = self
+                .grid
+                .remove_element_without_tuning(&grid_coordinate)
+                .map_err(|_| GridError::UnoccupiedError(coordinate))?;
+            self.grid
+                .store_element_without_tuning(&grid_target, element)?;
+            self.grid.apply_performance_tuning();
+            Ok(*marker.position())
+        } else if self.grid.wrap_mode() == WrapMode::Toroidal {
+            self.element(&coordinate)?;
+            Ok(coordinate)
+        } else {
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                marker.coordinate_in_direction(direction, 1),
+                direction,
+                None,
+            )))
+        }
+    }
+
+    /// Move all elements above or on the provided row in the provided direction. See
+    /// [`Grid::move_elements_above_row_in_direction`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in case of out of bounds or collision.
+    pub fn move_elements_above_row_in_direction(
+        &mut self,
+        y_coord: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        let row = self
+            .grid_line(Coordinate {
+                x: self.bounds.x_min_boundary(),
+                y: y_coord,
+            })?
+            .y;
+        self.grid
+            .move_elements_above_row_in_direction(row, direction)
+            .map_err(|error| self.offset_error(error))
+    }
+
+    /// Move all elements below or on the provided row in the provided direction. See
+    /// [`Grid::move_elements_below_row_in_direction`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in case of out of bounds or collision.
+    pub fn move_elements_below_row_in_direction(
+        &mut self,
+        y_coord: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        let row = self
+            .grid_line(Coordinate {
+                x: self.bounds.x_min_boundary(),
+                y: y_coord,
+            })?
+            .y;
+        self.grid
+            .move_elements_below_row_in_direction(row, direction)
+            .map_err(|error| self.offset_error(error))
+    }
+
+    /// Move all elements left of or on the provided column in the provided direction. See
+    /// [`Grid::move_elements_left_of_column_in_direction`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in case of out of bounds or collision.
+    pub fn move_elements_left_of_column_in_direction(
+        &mut self,
+        x_coord: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        let column = self
+            .grid_line(Coordinate {
+                x: x_coord,
+                y: self.bounds.y_min_boundary(),
+            })?
+            .x;
+        self.grid
+            .move_elements_left_of_column_in_direction(column, direction)
+            .map_err(|error| self.offset_error(error))
+    }
+
+    /// Move all elements right of or on the provided column in the provided direction. See
+    /// [`Grid::move_elements_right_of_column_in_direction`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in case of out of bounds or collision.
+    pub fn move_elements_right_of_column_in_direction(
+        &mut self,
+        x_coord: i32,
+        direction: AbsoluteDirection,
+    ) -> Result<(), GridError> {
+        let column = self
+            .grid_line(Coordinate {
+                x: x_coord,
+                y: self.bounds.y_min_boundary(),
+            })?
+            .x;
+        self.grid
+            .move_elements_right_of_column_in_direction(column, direction)
+            .map_err(|error| self.offset_error(error))
+    }
+
+    /// All coordinates of the grid along with their elements, from west to east, north to south.
+    pub fn iter_new(&self) -> impl Iterator<Item = (Coordinate, Option<&T>)> {
+        self.grid
+            .iter_new()
+            .map(|(coordinate, element)| (self.offset_coordinate(coordinate), element))
+    }
+
+    /// All coordinates of the grid along with mutable references to their elements, from west to
+    /// east, north to south.
+    pub fn iter_mut_new(&mut self) -> impl Iterator<Item = (Coordinate, Option<&mut T>)> {
+        let bounds = self.bounds;
+        let grid_bounds = self.grid.bounds();
+        self.grid.iter_mut_new().map(move |(coordinate, element)| {
+            (translate(coordinate, &grid_bounds, &bounds), element)
+        })
+    }
+
+    /// The occupied coordinates of the grid along with their elements, from west to east, north
+    /// to south.
+    pub fn iter_elements_new(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.grid
+            .iter_elements_new()
+            .map(|(coordinate, element)| (self.offset_coordinate(coordinate), element))
+    }
+
+    /// The occupied coordinates of the grid along with mutable references to their elements, from
+    /// west to east, north to south.
+    pub fn iter_mut_elements_new(&mut self) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        let bounds = self.bounds;
+        let grid_bounds = self.grid.bounds();
+        self.grid
+            .iter_mut_elements_new()
+            .map(move |(coordinate, element)| {
+                (translate(coordinate, &grid_bounds, &bounds), element)
+            })
+    }
+
+    /// A string where '#' marks an occupied element and '.' marks an empty element with one line
+    /// for each row in the grid.
+    pub fn element_statuses(&self) -> String {
+        self.grid.element_statuses()
+    }
+
+    /// The coordinate that a position refers to: the wrapped coordinate for a toroidal grid, and
+    /// the position itself otherwise.
+    fn resolve<C: Positioned>(&self, coordinate: &C) -> Coordinate {
+        match self.grid.wrap_mode() {
+            WrapMode::Bounded => *coordinate.position(),
+            WrapMode::Toroidal => self.wrap(coordinate),
+        }
+    }
+
+    /// The coordinate in the origin-centered grid that corresponds to a coordinate of this grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds and the grid is not
+    /// toroidal.
+    fn grid_coordinate<C: Positioned>(
+        &self,
+        coordinate: &C,
+    ) -> Result<Coordinate, OutOfBoundsError> {
+        let coordinate = self.resolve(coordinate);
+        self.coordinate_to_index(&coordinate)?;
+        Ok(translate(coordinate, &self.bounds, &self.grid.bounds()))
+    }
+
+    /// The coordinate in the origin-centered grid of a coordinate on a row or column that is moved.
+    /// Unlike [`grid_coordinate`](Self::grid_coordinate()), the coordinate does not wrap, like
+    /// with the bulk moves of a [`Grid`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds.
+    fn grid_line(&self, line: Coordinate) -> Result<Coordinate, OutOfBoundsError> {
+        self.coordinate_to_index(&line)?;
+        Ok(translate(line, &self.bounds, &self.grid.bounds()))
+    }
+
+    /// An error of the origin-centered grid with its coordinate in the coordinates of this grid.
+    /// A coordinate past the bounds is clamped to the range of `i32`.
+    fn offset_error(&self, error: GridError) -> GridError {
+        let GridError::OutOfBoundsError(error) = error else {
+            return error;
+        };
+        let translate_axis = |value: i32, from_min: i32, to_min: i32| {
+            let value = i64::from(value) - i64::from(from_min) + i64::from(to_min);
+            value.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+        };
+        let grid_bounds = self.grid.bounds();
+        let position = Coordinate {
+            x: translate_axis(
+                error.position().x,
+                grid_bounds.x_min_boundary(),
+                self.bounds.x_min_boundary(),
+            ),
+            y: translate_axis(
+                error.position().y,
+                grid_bounds.y_min_boundary(),
+                self.bounds.y_min_boundary(),
+            ),
+        };
+        GridError::OutOfBoundsError(OutOfBoundsError::new(
+            position,
+            error.first_out_of_bounds_direction(),
+            error.second_out_of_bounds_direction(),
+        ))
+    }
+
+    /// The coordinate of this grid that corresponds to a coordinate in the origin-centered grid.
+    fn offset_coordinate(&self, coordinate: Coordinate) -> Coordinate {
+        translate(coordinate, &self.grid.bounds(), &self.bounds)
+    }
+}
+
+/// Translate a coordinate within `from` to the coordinate at the same place within `to`. The
+/// bounds have the same counts.
+fn translate<F: Bounded, B: Bounded>(coordinate: Coordinate, from: &F, to: &B) -> Coordinate {
+    let translate_axis = |value: i32, from_min: i32, to_min: i32| {
+        let value = i64::from(value) - i64::from(from_min) + i64::from(to_min);
+        i32::try_from(value).expect("the bounds have the same counts")
+    };
+    Coordinate {
+        x: translate_axis(coordinate.x, from.x_min_boundary(), to.x_min_boundary()),
+        y: translate_axis(coordinate.y, from.y_min_boundary(), to.y_min_boundary()),
+    }
+}
+
+impl<T> From<Grid<T>> for OffsetGrid<T> {
+    /// An offset grid with the bounds of the grid.
+    fn from(grid: Grid<T>) -> Self {
+        let bounds = grid.bounds();
+        Self::from_grid(grid, &bounds).expect("the grid has its own counts")
+    }
+}
+
+impl<T> OriginCenteredness for OffsetGrid<T> {
+    type Distinguisher = MaybeOriginCentered;
+}
+
+impl<T> MaybeOriginBounded for OffsetGrid<T> {
+    fn x_min(&self) -> i32 {
+        self.bounds.x_min_boundary()
+    }
+
+    fn x_max(&self) -> i32 {
+        self.bounds.x_max_boundary()
+    }
+
+    fn y_min(&self) -> i32 {
+        self.bounds.y_min_boundary()
+    }
+
+    fn y_max(&self) -> i32 {
+        self.bounds.y_max_boundary()
+    }
+}
+
+impl<T> fmt::Debug for OffsetGrid<T> {
+    /// The bounds of the grid followed by its [element statuses](OffsetGrid::element_statuses).
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "OffsetGrid {:?}", self.bounds)?;
+        formatter.write_str(&self.element_statuses())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+    use crate::OriginCenteredBounds;
+
+    /// A 4x3 grid in screen-like coordinates from (0, 0) to (3, 2).
+    fn screen() -> OffsetGrid<char> {
+        let mut grid = OffsetGrid::from_bounds(&Bounds::from_boundaries(0, 3, 0, 2));
+        grid.store_element(&Coordinate { x: 0, y: 0 }, 'a').unwrap();
+        grid.store_element(&Coordinate { x: 3, y: 2 }, 'b').unwrap();
+        grid
+    }
+
+    #[test]
+    fn elements_use_the_coordinates_of_the_bounds() {
+        let mut grid = screen();
+        assert_eq!(grid.x_count(), 4);
+        assert_eq!(grid.element(&Coordinate { x: 0, y: 0 }), Ok(&'a'));
+        assert_eq!(grid.element_statuses(), "...#\n....\n#...");
+        assert_eq!(
+            grid.element(&Coordinate { x: 1, y: 0 }),
+            Err(GridError::UnoccupiedError(Coordinate { x: 1, y: 0 }))
+        );
+        assert_eq!(
+            grid.element(&Coordinate { x: -1, y: 0 }),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: -1, y: 0 },
+                AbsoluteDirection::West,
+                None
+            )))
+        );
+
+        *grid.get_mut_element(&Coordinate { x: 3, y: 2 }).unwrap() = 'c';
+        assert_eq!(grid.remove_element(&Coordinate { x: 3, y: 2 }), Ok('c'));
+        assert_eq!(
+            grid.remove_element(&Coordinate { x: 3, y: 2 }),
+            Err(GridError::UnoccupiedError(Coordinate { x: 3, y: 2 }))
+        );
+        assert!(grid.store_element(&Coordinate { x: 4, y: 2 }, 'd').is_err());
+    }
+
+    #[test]
+    fn iteration_uses_the_coordinates_of_the_bounds() {
+        let mut grid = screen();
+        let coordinates = grid
+            .iter_new()
+            .map(|(coordinate, _)| coordinate)
+            .collect::<Vec<_>>();
+        assert_eq!(coordinates.len(), 12);
+        assert_eq!(coordinates[0], Coordinate { x: 0, y: 2 });
+        assert_eq!(coordinates[11], Coordinate { x: 3, y: 0 });
+
+        for (coordinate, element) in grid.iter_mut_elements_new() {
+            *element = if coordinate.x == 0 { 'x' } else { 'y' };
+        }
+        assert_eq!(
+            grid.iter_elements_new().collect::<Vec<_>>(),
+            [
+                (Coordinate { x: 3, y: 2 }, &'y'),
+                (Coordinate { x: 0, y: 0 }, &'x')
+            ]
+        );
+        assert_eq!(
+            grid.iter_mut_new()
+                .filter(|(_, element)| element.is_none())
+                .count(),
+            10
+        );
+    }
+
+    #[test]
+    fn movement() {
+        let mut grid = screen();
+        assert_eq!(
+            grid.move_element_in_direction(&Coordinate { x: 0, y: 0 }, AbsoluteDirection::North),
+            Ok(Coordinate { x: 0, y: 1 })
+        );
+        assert_eq!(
+            grid.move_element_in_direction(&Coordinate { x: 3, y: 2 }, AbsoluteDirection::North),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: 3, y: 3 },
+                AbsoluteDirection::North,
+                None
+            )))
+        );
+        assert_eq!(
+            grid.move_element_in_direction(&Coordinate { x: 2, y: 2 }, AbsoluteDirection::West),
+            Err(GridError::UnoccupiedError(Coordinate { x: 2, y: 2 }))
+        );
+        assert_eq!(grid.element_statuses(), "...#\n#...\n....");
+    }
+
+    #[test]
+    fn bulk_movement() {
+        let mut grid = screen();
+        grid.move_elements_above_row_in_direction(1, AbsoluteDirection::South)
+            .unwrap();
+        grid.move_elements_right_of_column_in_direction(2, AbsoluteDirection::West)
+            .unwrap();
+        grid.move_elements_left_of_column_in_direction(0, AbsoluteDirection::East)
+            .unwrap();
+        assert_eq!(grid.element(&Coordinate { x: 2, y: 1 }), Ok(&'b'));
+        assert_eq!(grid.element(&Coordinate { x: 1, y: 0 }), Ok(&'a'));
+
+        assert_eq!(
+            grid.move_elements_below_row_in_direction(0, AbsoluteDirection::South),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: 1, y: -1 },
+                AbsoluteDirection::South,
+                None
+            )))
+        );
+        assert_eq!(
+            grid.move_elements_left_of_column_in_direction(1, AbsoluteDirection::North),
+            Ok(())
+        );
+        assert_eq!(
+            grid.move_elements_right_of_column_in_direction(2, AbsoluteDirection::West),
+            Err(GridError::CollisionError)
+        );
+        assert_eq!(
+            grid.move_elements_above_row_in_direction(3, AbsoluteDirection::South),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: 0, y: 3 },
+                AbsoluteDirection::North,
+                None
+            )))
+        );
+        assert_eq!(
+            grid.element_statuses(),
+            "....
+.##.
+...."
+        );
+    }
+
+    #[test]
+    fn toroidal_movement() {
+        let grid = screen().into_grid().with_wrap_mode(WrapMode::Toroidal);
+        let mut grid = OffsetGrid::from_grid(grid, &Bounds::from_boundaries(0, 3, 0, 2)).unwrap();
+        assert_eq!(
+            grid.move_element_in_direction(&Coordinate { x: 3, y: 2 }, AbsoluteDirection::East),
+            Ok(Coordinate { x: 0, y: 2 })
+        );
+        assert_eq!(grid.element(&Coordinate { x: 4, y: -1 }), Ok(&'b'));
+    }
+
+    #[test]
+    fn conversion_to_and_from_grid() {
+        let grid = screen().into_grid();
+        assert_eq!(
+            grid.bounds(),
+            OriginCenteredBounds::new(
+                AxisCount::try_from(4).unwrap(),
+                AxisCount::try_from(3).unwrap()
+            )
+        );
+        assert_eq!(grid.element(&grid.southwest_corner()), Ok(&'a'));
+
+        let same = OffsetGrid::from(grid.clone());
+        assert_eq!(same.bounds(), Bounds::from_boundaries(-1, 2, -1, 1));
+        assert_eq!(same.element(&Coordinate { x: -1, y: -1 }), Ok(&'a'));
+        assert_eq!(
+            OffsetGrid::from_grid(grid, &Bounds::from_boundaries(0, 2, 0, 2)),
+            Err(GridError::ShapeMismatchError)
+        );
+    }
+
+    #[test]
+    fn extreme_bounds() {
+        let bounds = Bounds::from_boundaries(i32::MAX - 1, i32::MAX, i32::MIN, i32::MIN + 2);
+        let mut grid = OffsetGrid::from_bounds(&bounds);
+        grid.store_element(&bounds.southeast_corner(), ()).unwrap();
+        assert_eq!(
+            grid.iter_elements_new().next(),
+            Some((bounds.southeast_corner(), &()))
+        );
+        assert_eq!(grid.element_statuses(), "..\n..\n.#");
+        assert_eq!(
+            grid.move_elements_right_of_column_in_direction(i32::MAX, AbsoluteDirection::East),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate {
+                    x: i32::MAX,
+                    y: i32::MIN
+                },
+                AbsoluteDirection::East,
+                None
+            )))
+        );
+    }
+}
@@ -1,7 +1,101 @@
-#[allow(unused)]
-#[derive(Debug)]
+/// Grids with at most this many coordinates always use dense storage when tuned for
+/// [`PerformanceTuning::Auto`].
+const AUTO_DENSE_CELL_COUNT: u64 = 4096;
+
+/// Dense storage is abandoned when the share of occupied coordinates drops below `1 / n`.
+const AUTO_SPARSE_FILL_DIVISOR: u64 = 32;
+
+/// Sparse storage is abandoned when the share of occupied coordinates exceeds `1 / n`.
+const AUTO_DENSE_FILL_DIVISOR: u64 = 8;
+
+/// Determines how a [`Grid`](crate::Grid) stores its elements.
+///
+/// A grid either keeps a dense row-major store with one entry per coordinate, or a sparse store
+/// that only holds the occupied coordinates. The grid API is identical for both representations;
+/// only the memory use and the cost of the operations differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PerformanceTuning {
+    /// Keep only the occupied coordinates in memory.
+    ///
+    /// Memory use is proportional to the number of elements rather than to the area of the grid,
+    /// which makes it possible to create very large, mostly empty grids. Element lookups are
+    /// logarithmic in the number of elements.
     Memory,
+
+    /// Keep an entry for every coordinate in memory, along with lookup tables that trade memory for
+    /// speed.
+    ///
+    /// Memory use is proportional to the area of the grid and element lookups are constant time.
+    /// The grid additionally caches the coordinate of every index, the index of every row and a
+    /// bitmap of the occupied coordinates. This makes mutable iteration avoid recomputing
+    /// coordinates, and lets [`empty_rows`](crate::Grid::empty_rows()),
+    /// [`empty_columns`](crate::Grid::empty_columns()) and
+    /// [`iter_elements_new`](crate::Grid::iter_elements_new()) skip empty coordinates without
+    /// looking at each one.
     Speed,
+
+    /// Switch between dense and sparse storage depending on the size of the grid and the share of
+    /// occupied coordinates.
+    ///
+    /// Small grids always use dense storage. Larger grids start out sparse, become dense once more
+    /// than 1/8 of their coordinates are occupied, and become sparse again once fewer than 1/32 of
+    /// their coordinates are occupied.
+    #[default]
     Auto,
 }
+
+impl PerformanceTuning {
+    /// Whether a grid with `cell_count` coordinates and `occupied` elements should use dense
+    /// storage. `is_dense` is the current representation, which is used as a tie breaker to avoid
+    /// switching back and forth around a single threshold.
+    pub(super) fn prefers_dense(self, cell_count: u64, occupied: usize, is_dense: bool) -> bool {
+        match self {
+            PerformanceTuning::Memory => false,
+            PerformanceTuning::Speed => true,
+            PerformanceTuning::Auto => {
+                let occupied = occupied as u64;
+                if cell_count <= AUTO_DENSE_CELL_COUNT {
+                    true
+                } else if is_dense {
+                    occupied.saturating_mul(AUTO_SPARSE_FILL_DIVISOR) >= cell_count
+                } else {
+                    occupied.saturating_mul(AUTO_DENSE_FILL_DIVISOR) > cell_count
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_never_prefers_dense() {
+        assert!(!PerformanceTuning::Memory.prefers_dense(1, 1, true));
+        assert!(!PerformanceTuning::Memory.prefers_dense(u64::MAX, 0, false));
+    }
+
+    #[test]
+    fn speed_always_prefers_dense() {
+        assert!(PerformanceTuning::Speed.prefers_dense(1, 0, false));
+        assert!(PerformanceTuning::Speed.prefers_dense(u64::MAX, 0, false));
+    }
+
+    #[test]
+    fn auto_prefers_dense_for_small_grids() {
+        assert!(PerformanceTuning::Auto.prefers_dense(AUTO_DENSE_CELL_COUNT, 0, false));
+        assert!(!PerformanceTuning::Auto.prefers_dense(AUTO_DENSE_CELL_COUNT + 1, 0, false));
+    }
+
+    #[test]
+    fn auto_switches_with_hysteresis() {
+        let cell_count = 32_000;
+        // 1/16 of the cells are occupied, which is between both thresholds.
+        assert!(PerformanceTuning::Auto.prefers_dense(cell_count, 2_000, true));
+        assert!(!PerformanceTuning::Auto.prefers_dense(cell_count, 2_000, false));
+
+        assert!(!PerformanceTuning::Auto.prefers_dense(cell_count, 999, true));
+        assert!(PerformanceTuning::Auto.prefers_dense(cell_count, 4_001, false));
+    }
+}
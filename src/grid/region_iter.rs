@@ -0,0 +1,383 @@
+use super::Grid;
+use crate::AbsoluteDirection;
+use crate::Bounds;
+use crate::Coordinate;
+use crate::OutOfBoundsError;
+use crate::bounded::Bounded;
+
+impl<T> Grid<T> {
+    /// All coordinates of a row along with their elements, from west to east.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the row is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let mut grid = grid!(3, 3);
+    /// grid.store_element(&Coordinate { x: 1, y: 1 }, ());
+    ///
+    /// let occupied = grid.row(1).unwrap().filter(|(_, element)| element.is_some()).count();
+    /// assert_eq!(occupied, 1);
+    /// assert!(grid.row(2).is_err());
+    /// ```
+    pub fn row(
+        &self,
+        y: i32,
+    ) -> Result<impl Iterator<Item = (Coordinate, Option<&T>)> + '_, OutOfBoundsError> {
+        self.check_row(y)?;
+        Ok(self.row_unchecked(y))
+    }
+
+    /// Mutable access to all coordinates of a row, from west to east.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the row is out of bounds.
+    pub fn row_mut(
+        &mut self,
+        y: i32,
+    ) -> Result<impl Iterator<Item = (Coordinate, Option<&mut T>)> + '_, OutOfBoundsError> {
+        self.check_row(y)?;
+        let row = self.row_bounds(y);
+        Ok(self.storage.region_mut(&self.bounds, row))
+    }
+
+    /// All coordinates of a column along with their elements, from north to south.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the column is out of bounds.
+    pub fn column(
+        &self,
+        x: i32,
+    ) -> Result<impl Iterator<Item = (Coordinate, Option<&T>)> + '_, OutOfBoundsError> {
+        self.check_column(x)?;
+        Ok(self.column_unchecked(x))
+    }
+
+    /// Mutable access to all coordinates of a column, from north to south.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the column is out of bounds.
+    pub fn column_mut(
+        &mut self,
+        x: i32,
+    ) -> Result<impl Iterator<Item = (Coordinate, Option<&mut T>)> + '_, OutOfBoundsError> {
+        self.check_column(x)?;
+        let column = self.column_bounds(x);
+        Ok(self.storage.region_mut(&self.bounds, column))
+    }
+
+    /// All coordinates of a region along with their elements, from west to east, north to south.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any part of the region is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Bounds;
+    /// use tudi::Coordinate;
+    /// use tudi::grid;
+    /// let mut grid = grid!(5, 5);
+    /// grid.store_element(&Coordinate { x: 1, y: 1 }, ());
+    ///
+    /// let region = Bounds::from_boundaries(0, 2, 0, 2);
+    /// assert_eq!(grid.region(&region).unwrap().count(), 9);
+    /// ```
+    pub fn region<B: Bounded>(
+        &self,
+        region: &B,
+    ) -> Result<impl Iterator<Item = (Coordinate, Option<&T>)> + '_, OutOfBoundsError> {
+        let region = self.check_region(region)?;
+        Ok(self.storage.region(self.bounds, region))
+    }
+
+    /// Mutable access to all coordinates of a region, from west to east, north to south.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any part of the region is out of bounds.
+    pub fn region_mut<B: Bounded>(
+        &mut self,
+        region: &B,
+    ) -> Result<impl Iterator<Item = (Coordinate, Option<&mut T>)> + '_, OutOfBoundsError> {
+        let region = self.check_region(region)?;
+        Ok(self.storage.region_mut(&self.bounds, region))
+    }
+
+    /// An iterator over each row of the grid, from north to south.
+    pub fn rows(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl Iterator<Item = (Coordinate, Option<&T>)> + '_> + '_
+    {
+        (self.y_min_boundary()..=self.y_max_boundary())
+            .rev()
+            .map(|y| self.row_unchecked(y))
+    }
+
+    /// An iterator over each column of the grid, from west to east.
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl Iterator<Item = (Coordinate, Option<&T>)> + '_> + '_
+    {
+        (self.x_min_boundary()..=self.x_max_boundary()).map(|x| self.column_unchecked(x))
+    }
+
+    /// Mutable access to each row of the grid, from north to south.
+    pub fn rows_mut(
+        &mut self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Coordinate, Option<&mut T>)>> {
+        self.storage.rows_mut(&self.bounds)
+    }
+
+    /// Mutable access to each column of the grid, from west to east.
+    pub fn columns_mut(
+        &mut self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Coordinate, Option<&mut T>)>> {
+        self.storage.columns_mut(&self.bounds)
+    }
+
+    fn row_unchecked(&self, y: i32) -> impl Iterator<Item = (Coordinate, Option<&T>)> + '_ {
+        self.storage.region(self.bounds, self.row_bounds(y))
+    }
+
+    fn column_unchecked(&self, x: i32) -> impl Iterator<Item = (Coordinate, Option<&T>)> + '_ {
+        self.storage.region(self.bounds, self.column_bounds(x))
+    }
+
+    fn row_bounds(&self, y: i32) -> Bounds {
+        Bounds::from_boundaries(self.x_min_boundary(), self.x_max_boundary(), y, y)
+    }
+
+    fn column_bounds(&self, x: i32) -> Bounds {
+        Bounds::from_boundaries(x, x, self.y_min_boundary(), self.y_max_boundary())
+    }
+
+    fn check_row(&self, y: i32) -> Result<(), OutOfBoundsError> {
+        if y > self.y_max_boundary() {
+            Err(OutOfBoundsError::new(
+                Coordinate { x: 0, y },
+                AbsoluteDirection::North,
+                None,
+            ))
+        } else if y < self.y_min_boundary() {
+            Err(OutOfBoundsError::new(
+                Coordinate { x: 0, y },
+                AbsoluteDirection::South,
+                None,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_column(&self, x: i32) -> Result<(), OutOfBoundsError> {
+        if x > self.x_max_boundary() {
+            Err(OutOfBoundsError::new(
+                Coordinate { x, y: 0 },
+                AbsoluteDirection::East,
+                None,
+            ))
+        } else if x < self.x_min_boundary() {
+            Err(OutOfBoundsError::new(
+                Coordinate { x, y: 0 },
+                AbsoluteDirection::West,
+                None,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+    use crate::PerformanceTuning;
+    use crate::grid::generic_grid::tests::TUNINGS;
+    use crate::grid::generic_grid::tests::checkered_grid;
+
+    /// The coordinates of a region from west to east, north to south, along with the elements
+    /// found by looking up each coordinate.
+    fn expected(grid: &Grid<i32>, region: Bounds) -> Vec<(Coordinate, Option<i32>)> {
+        let mut result = Vec::new();
+        for y in (region.y_min_boundary()..=region.y_max_boundary()).rev() {
+            for x in region.x_min_boundary()..=region.x_max_boundary() {
+                let coordinate = Coordinate { x, y };
+                result.push((coordinate, grid.element_unchecked(&coordinate).copied()));
+            }
+        }
+        result
+    }
+
+    fn owned<'a>(
+        iter: impl Iterator<Item = (Coordinate, Option<&'a i32>)>,
+    ) -> Vec<(Coordinate, Option<i32>)> {
+        iter.map(|(coordinate, element)| (coordinate, element.copied()))
+            .collect()
+    }
+
+    fn owned_mut<'a>(
+        iter: impl Iterator<Item = (Coordinate, Option<&'a mut i32>)>,
+    ) -> Vec<(Coordinate, Option<i32>)> {
+        iter.map(|(coordinate, element)| (coordinate, element.copied()))
+            .collect()
+    }
+
+    #[test]
+    fn row_and_column() {
+        for performance_tuning in TUNINGS {
+            let mut grid = checkered_grid(5, 4, performance_tuning);
+            for y in -1..=2 {
+                let expected = expected(&grid, Bounds::from_boundaries(-2, 2, y, y));
+                assert_eq!(owned(grid.row(y).unwrap()), expected);
+                assert_eq!(owned_mut(grid.row_mut(y).unwrap()), expected);
+            }
+            for x in -2..=2 {
+                let expected = expected(&grid, Bounds::from_boundaries(x, x, -1, 2));
+                assert_eq!(owned(grid.column(x).unwrap()), expected);
+                assert_eq!(owned_mut(grid.column_mut(x).unwrap()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn region() {
+        for performance_tuning in TUNINGS {
+            let mut grid = checkered_grid(5, 4, performance_tuning);
+            for region in [
+                Bounds::from_boundaries(-1, 1, 0, 1),
+                Bounds::from_boundaries(-2, 2, -1, 2),
+                Bounds::from_boundaries(2, 2, -1, -1),
+                Bounds::from_boundaries(0, 1, -1, 2),
+            ] {
+                let expected = expected(&grid, region);
+                assert_eq!(owned(grid.region(&region).unwrap()), expected);
+                assert_eq!(owned_mut(grid.region_mut(&region).unwrap()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        for performance_tuning in TUNINGS {
+            let mut grid = checkered_grid(5, 4, performance_tuning);
+            let rows = grid.rows().map(owned).collect::<Vec<_>>();
+            let columns = grid.columns().map(owned).collect::<Vec<_>>();
+            assert_eq!(rows.len(), 4);
+            assert_eq!(columns.len(), 5);
+            assert_eq!(rows.concat(), owned(grid.iter_new()));
+            for (x, column) in (-2..=2).zip(&columns) {
+                assert_eq!(*column, owned(grid.column(x).unwrap()));
+            }
+
+            assert_eq!(grid.rows_mut().map(owned_mut).collect::<Vec<_>>(), rows);
+            assert_eq!(
+                grid.columns_mut().map(owned_mut).collect::<Vec<_>>(),
+                columns
+            );
+        }
+    }
+
+    #[test]
+    fn mutable_iterators_write_through() {
+        for performance_tuning in TUNINGS {
+            let mut grid = checkered_grid(5, 4, performance_tuning);
+            for (_, element) in grid.row_mut(0).unwrap() {
+                if let Some(element) = element {
+                    *element += 100;
+                }
+            }
+            for (_, element) in grid.column_mut(-2).unwrap() {
+                if let Some(element) = element {
+                    *element += 1000;
+                }
+            }
+            let mut columns = grid.columns_mut().collect::<Vec<_>>();
+            let (first, rest) = columns.split_at_mut(1);
+            // columns can be mutated independently of each other.
+            for ((_, west), (_, east)) in first[0].by_ref().zip(rest[3].by_ref()) {
+                if let (Some(west), Some(east)) = (west, east) {
+                    std::mem::swap(west, east);
+                }
+            }
+            drop(columns);
+
+            assert_eq!(grid.element(&Coordinate { x: 0, y: 0 }), Ok(&100));
+            assert_eq!(grid.element(&Coordinate { x: -2, y: 0 }), Ok(&120));
+            assert_eq!(grid.element(&Coordinate { x: 2, y: 0 }), Ok(&1080));
+            assert_eq!(grid.element(&Coordinate { x: 2, y: 2 }), Ok(&982));
+        }
+    }
+
+    #[test]
+    fn lines_mut_of_a_large_sparse_grid_are_lazy() {
+        let count = AxisCount::try_from(1_000_000).unwrap();
+        let mut grid =
+            Grid::with_count(count, count).with_performance_tuning(PerformanceTuning::Memory);
+        grid.store_element(&grid.northwest_corner(), 1).unwrap();
+
+        let mut rows = grid.rows_mut();
+        let (_, northwest) = rows.next().unwrap().next().unwrap();
+        *northwest.unwrap() += 1;
+        drop(rows);
+        let (_, northwest) = grid.columns_mut().next().unwrap().next().unwrap();
+        *northwest.unwrap() += 1;
+        assert_eq!(grid.element(&grid.northwest_corner()), Ok(&3));
+    }
+
+    #[test]
+    fn out_of_bounds_should_err() {
+        let mut grid = checkered_grid(5, 4, PerformanceTuning::Auto);
+        assert_eq!(
+            grid.row(3).err(),
+            Some(OutOfBoundsError::new(
+                Coordinate { x: 0, y: 3 },
+                AbsoluteDirection::North,
+                None
+            ))
+        );
+        assert_eq!(
+            grid.row_mut(-2).err(),
+            Some(OutOfBoundsError::new(
+                Coordinate { x: 0, y: -2 },
+                AbsoluteDirection::South,
+                None
+            ))
+        );
+        assert_eq!(
+            grid.column(3).err(),
+            Some(OutOfBoundsError::new(
+                Coordinate { x: 3, y: 0 },
+                AbsoluteDirection::East,
+                None
+            ))
+        );
+        assert_eq!(
+            grid.column_mut(-3).err(),
+            Some(OutOfBoundsError::new(
+                Coordinate { x: -3, y: 0 },
+                AbsoluteDirection::West,
+                None
+            ))
+        );
+        assert_eq!(
+            grid.region(&Bounds::from_boundaries(-3, 0, 0, 3)).err(),
+            Some(OutOfBoundsError::new(
+                Coordinate { x: -3, y: 3 },
+                AbsoluteDirection::North,
+                Some(AbsoluteDirection::West)
+            ))
+        );
+        assert!(
+            grid.region_mut(&Bounds::from_boundaries(0, 3, -1, 0))
+                .is_err()
+        );
+    }
+}
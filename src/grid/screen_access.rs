@@ -0,0 +1,125 @@
+use super::Grid;
+use super::GridError;
+use crate::ScreenCoordinate;
+use crate::bounded::Bounded;
+
+impl<T> Grid<T> {
+    /// A reference to the element at a screen coordinate, counted from the northwest corner of
+    /// the grid with rows growing south. See [`Grid::element`].
+    ///
+    /// Screen coordinates past the bounds are out of bounds, also on a toroidal grid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the screen coordinate is out of bounds.
+    ///
+    /// This method returns an error if the screen coordinate does not contain an element.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tudi::Grid;
+    /// use tudi::ScreenCoordinate;
+    /// let mut grid = Grid::from_str_by_map("a..\n..b", &HashMap::from([('a', 'a'), ('b', 'b')]))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(grid.element_at_screen(ScreenCoordinate { column: 2, row: 1 }), Ok(&'b'));
+    /// grid.store_element_at_screen(ScreenCoordinate { column: 1, row: 0 }, 'c').unwrap();
+    /// assert_eq!(grid.element_statuses(), "##.\n..#");
+    /// ```
+    pub fn element_at_screen(&self, screen: ScreenCoordinate) -> Result<&T, GridError> {
+        self.element(&self.screen_to_coordinate(screen)?)
+    }
+
+    /// A mutable reference to the element at a screen coordinate. See [`Grid::get_mut_element`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the screen coordinate is out of bounds.
+    ///
+    /// This method returns an error if the screen coordinate does not contain an element.
+    pub fn get_mut_element_at_screen(
+        &mut self,
+        screen: ScreenCoordinate,
+    ) -> Result<&mut T, GridError> {
+        let coordinate = self.screen_to_coordinate(screen)?;
+        self.get_mut_element(&coordinate)
+    }
+
+    /// Store an element at a screen coordinate, returning the element that was previously stored
+    /// there. See [`Grid::store_element`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the screen coordinate is out of bounds.
+    pub fn store_element_at_screen(
+        &mut self,
+        screen: ScreenCoordinate,
+        element: T,
+    ) -> Result<Option<T>, GridError> {
+        let coordinate = self.screen_to_coordinate(screen)?;
+        self.store_element(&coordinate, element)
+    }
+
+    /// Remove the element at a screen coordinate. See [`Grid::remove_element`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the screen coordinate is out of bounds.
+    ///
+    /// This method returns an error if the screen coordinate does not contain an element.
+    pub fn remove_element_at_screen(&mut self, screen: ScreenCoordinate) -> Result<T, GridError> {
+        let coordinate = self.screen_to_coordinate(screen)?;
+        self.remove_element(&coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbsoluteDirection;
+    use crate::Coordinate;
+    use crate::OutOfBoundsError;
+    use crate::WrapMode;
+    use crate::grid;
+
+    #[test]
+    fn screen_access_matches_the_coordinates() {
+        let mut grid = grid!(4, 3);
+        let northwest = ScreenCoordinate { column: 0, row: 0 };
+        let southeast = ScreenCoordinate { column: 3, row: 2 };
+
+        assert_eq!(grid.store_element_at_screen(northwest, 1), Ok(None));
+        assert_eq!(grid.store_element_at_screen(southeast, 2), Ok(None));
+        assert_eq!(grid.element(&grid.northwest_corner()), Ok(&1));
+        assert_eq!(grid.element(&grid.southeast_corner()), Ok(&2));
+
+        *grid.get_mut_element_at_screen(southeast).unwrap() += 1;
+        assert_eq!(grid.element_at_screen(southeast), Ok(&3));
+        assert_eq!(grid.remove_element_at_screen(northwest), Ok(1));
+        assert_eq!(
+            grid.element_at_screen(northwest),
+            Err(GridError::UnoccupiedError(grid.northwest_corner()))
+        );
+        assert_eq!(grid.element_statuses(), "....\n....\n...#");
+    }
+
+    #[test]
+    fn screen_coordinates_past_the_bounds_are_out_of_bounds() {
+        let mut grid = grid!(4, 3).with_wrap_mode(WrapMode::Toroidal);
+        let past_east = ScreenCoordinate { column: 4, row: 0 };
+        assert_eq!(
+            grid.store_element_at_screen(past_east, ()),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: 3, y: 1 },
+                AbsoluteDirection::East,
+                None
+            )))
+        );
+        assert!(
+            grid.remove_element_at_screen(ScreenCoordinate { column: 0, row: 3 })
+                .is_err()
+        );
+        assert_eq!(grid.iter_elements_new().count(), 0);
+    }
+}
@@ -0,0 +1,238 @@
+use super::Grid;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+/// The repeating part of a [`Simulation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    start: usize,
+    length: usize,
+}
+
+impl Cycle {
+    /// The first step whose state is part of the cycle.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The number of steps after which the states repeat.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
+
+/// Repeatedly applies a step function to a grid and detects when a state repeats.
+///
+/// Every state is kept, so once a cycle has been found, the state at any step can be returned
+/// without running the simulation up to it. The step function must be deterministic: the next
+/// state may only depend on the current one.
+///
+/// # Examples
+/// ```
+/// use tudi::Coordinate;
+/// use tudi::Simulation;
+/// use tudi::grid;
+/// let mut grid = grid!(3, 3);
+/// grid.store_element(&Coordinate { x: 1, y: 1 }, ());
+///
+/// let mut simulation = Simulation::new(grid, |grid| grid.rotate_clockwise());
+/// let cycle = simulation.find_cycle();
+/// assert_eq!((cycle.start(), cycle.length()), (0, 4));
+///
+/// // 1_000_000_001 quarter turns are the same as one.
+/// let state = simulation.state_at(1_000_000_001);
+/// assert!(state.element(&Coordinate { x: 1, y: -1 }).is_ok());
+/// ```
+pub struct Simulation<T, F> {
+    /// The state after each step, starting with the initial state.
+    states: Vec<Grid<T>>,
+    /// The indices of the states by their hash.
+    indices_by_hash: HashMap<u64, Vec<usize>>,
+    step: F,
+    cycle: Option<Cycle>,
+}
+
+impl<T: Clone + Hash + Eq, F: FnMut(&mut Grid<T>)> Simulation<T, F> {
+    /// Start a simulation at an initial state. The step function advances a state by one step.
+    pub fn new(initial: Grid<T>, step: F) -> Self {
+        let mut simulation = Self {
+            states: Vec::new(),
+            indices_by_hash: HashMap::new(),
+            step,
+            cycle: None,
+        };
+        simulation.record(initial);
+        simulation
+    }
+
+    /// The cycle of the simulation, if it has been found.
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// The number of steps that have been run.
+    pub fn steps_run(&self) -> usize {
+        self.states.len() - 1 + usize::from(self.cycle.is_some())
+    }
+
+    /// Run the simulation until a state repeats.
+    ///
+    /// This method never returns if the states never repeat. See
+    /// [`find_cycle_within`](Simulation::find_cycle_within()) for a bounded search.
+    pub fn find_cycle(&mut self) -> Cycle {
+        loop {
+            if let Some(cycle) = self.advance() {
+                return cycle;
+            }
+        }
+    }
+
+    /// Run the simulation until a state repeats, but for at most `max_steps` steps in total.
+    ///
+    /// Returns `None` if no state repeats within `max_steps` steps.
+    pub fn find_cycle_within(&mut self, max_steps: usize) -> Option<Cycle> {
+        while self.cycle.is_none() && self.steps_run() < max_steps {
+            self.advance();
+        }
+        self.cycle
+    }
+
+    /// The state after a number of steps.
+    ///
+    /// The simulation is run until it reaches the step or finds a cycle, whichever comes first.
+    /// Steps past the start of the cycle are mapped back into the cycle.
+    pub fn state_at(&mut self, step: u64) -> &Grid<T> {
+        while self.cycle.is_none() && self.states.len() as u64 <= step {
+            self.advance();
+        }
+
+        let index = match self.cycle {
+            Some(cycle) if step >= cycle.start as u64 => {
+                let offset = (step - cycle.start as u64) % cycle.length as u64;
+                cycle.start + offset as usize
+            }
+            _ => step as usize,
+        };
+        &self.states[index]
+    }
+
+    /// Run one step unless a cycle has already been found, and return the cycle if there is one.
+    fn advance(&mut self) -> Option<Cycle> {
+        if self.cycle.is_none() {
+            let mut next = self
+                .states
+                .last()
+                .expect("the initial state is always recorded")
+                .clone();
+            (self.step)(&mut next);
+
+            if let Some(start) = self.index_of(&next) {
+                self.cycle = Some(Cycle {
+                    start,
+                    length: self.states.len() - start,
+                });
+            } else {
+                self.record(next);
+            }
+        }
+        self.cycle
+    }
+
+    /// The index of an earlier state that is equal to the state.
+    fn index_of(&self, state: &Grid<T>) -> Option<usize> {
+        self.indices_by_hash
+            .get(&hash(state))?
+            .iter()
+            .copied()
+            .find(|index| self.states[*index] == *state)
+    }
+
+    fn record(&mut self, state: Grid<T>) {
+        self.indices_by_hash
+            .entry(hash(&state))
+            .or_default()
+            .push(self.states.len());
+        self.states.push(state);
+    }
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbsoluteDirection;
+    use crate::Connectivity;
+    use crate::Coordinate;
+    use crate::LifeRule;
+    use crate::WrapMode;
+    use crate::grid;
+    use std::collections::HashMap;
+
+    /// Move every element one step east, unless it is blocked.
+    fn push_east(grid: &mut Grid<()>) {
+        let coordinates = grid
+            .iter_elements_new()
+            .map(|(coordinate, _)| coordinate)
+            .collect::<Vec<_>>();
+        for coordinate in coordinates.into_iter().rev() {
+            let _ = grid.move_element_in_direction(&coordinate, AbsoluteDirection::East);
+        }
+    }
+
+    #[test]
+    fn cycle_after_a_transient() {
+        let mut grid = grid!(5, 1);
+        grid.store_element(&Coordinate { x: -2, y: 0 }, ()).unwrap();
+        let mut simulation = Simulation::new(grid, push_east);
+
+        assert_eq!(simulation.find_cycle_within(3), None);
+        assert_eq!(simulation.steps_run(), 3);
+
+        let cycle = simulation.find_cycle();
+        assert_eq!((cycle.start(), cycle.length()), (4, 1));
+        assert_eq!(simulation.steps_run(), 5);
+        assert_eq!(simulation.state_at(2).element_statuses(), "..#..");
+        assert_eq!(simulation.state_at(u64::MAX).element_statuses(), "....#");
+    }
+
+    #[test]
+    fn state_at_stops_at_the_step() {
+        let mut steps = 0;
+        let mut simulation = Simulation::new(grid!(1, 1), |_: &mut Grid<()>| steps += 1);
+        assert_eq!(simulation.state_at(0), &grid!(1, 1));
+        assert_eq!(simulation.cycle(), None);
+
+        simulation.state_at(7);
+        assert_eq!(simulation.cycle().map(|cycle| cycle.length()), Some(1));
+        drop(simulation);
+        assert_eq!(steps, 1);
+    }
+
+    #[test]
+    fn life_on_a_torus() {
+        let cells = HashMap::from([('#', ())]);
+        let glider = Grid::from_str_by_map(".#...\n..#..\n###..\n.....\n.....", &cells)
+            .unwrap()
+            .with_wrap_mode(WrapMode::Toroidal);
+        let mut simulation = Simulation::new(glider.clone(), |grid| {
+            grid.step(Connectivity::Euclid, LifeRule::CONWAY)
+        });
+
+        let cycle = simulation.find_cycle();
+        assert_eq!((cycle.start(), cycle.length()), (0, 20));
+        assert_eq!(simulation.state_at(1_000_000_000), &glider);
+
+        let mut expected = glider;
+        for _ in 0..(1_000_000_007 % 20) {
+            expected.step(Connectivity::Euclid, LifeRule::CONWAY);
+        }
+        assert_eq!(simulation.state_at(1_000_000_007), &expected);
+    }
+}
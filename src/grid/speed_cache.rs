@@ -0,0 +1,204 @@
+use crate::Coordinate;
+use crate::OriginCenteredBounds;
+use crate::OutOfBoundsError;
+use crate::Positioned;
+use crate::bounded::Bounded;
+
+const WORD_BITS: u64 = u64::BITS as u64;
+
+/// Lookup tables that a [`Grid`](super::Grid) tuned for
+/// [`PerformanceTuning::Speed`](super::PerformanceTuning::Speed) keeps next to its dense storage.
+///
+/// The tables are built for one set of bounds and have to be rebuilt whenever the bounds of the
+/// grid change. The occupancy bitmap has to be updated whenever an element is stored at an empty
+/// coordinate or removed.
+#[derive(Debug, Clone)]
+pub(super) struct SpeedCache {
+    bounds: OriginCenteredBounds,
+    /// The coordinate at each index.
+    coordinates: Vec<Coordinate>,
+    /// The index of the westernmost coordinate of each row, from north to south.
+    row_offsets: Vec<u64>,
+    /// One bit per index, set if the coordinate is occupied.
+    occupancy: Vec<u64>,
+}
+
+impl SpeedCache {
+    /// Build the tables for the bounds. `is_occupied` reports whether the coordinate at an index
+    /// is occupied.
+    pub(super) fn new(bounds: OriginCenteredBounds, is_occupied: impl Fn(u64) -> bool) -> Self {
+        let x_count = bounds.x_count().as_u64();
+        let cell_count = x_count * bounds.y_count().as_u64();
+
+        let mut coordinates = Vec::with_capacity(cell_count as usize);
+        for y in (bounds.y_min_boundary()..=bounds.y_max_boundary()).rev() {
+            for x in bounds.x_min_boundary()..=bounds.x_max_boundary() {
+                coordinates.push(Coordinate { x, y });
+            }
+        }
+
+        let row_offsets = (0..bounds.y_count().as_u64())
+            .map(|row| row * x_count)
+            .collect();
+
+        let mut occupancy = vec![0; cell_count.div_ceil(WORD_BITS) as usize];
+        for index in (0..cell_count).filter(|index| is_occupied(*index)) {
+            occupancy[(index / WORD_BITS) as usize] |= 1 << (index % WORD_BITS);
+        }
+
+        Self {
+            bounds,
+            coordinates,
+            row_offsets,
+            occupancy,
+        }
+    }
+
+    /// Whether the tables were built for the bounds.
+    pub(super) fn is_built_for(&self, bounds: &OriginCenteredBounds) -> bool {
+        self.bounds == *bounds
+    }
+
+    pub(super) fn coordinates(&self) -> &[Coordinate] {
+        &self.coordinates
+    }
+
+    /// The index of a coordinate, computed from the precomputed row offsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinate is out of bounds.
+    pub(super) fn coordinate_to_index<C: Positioned>(
+        &self,
+        coordinate: &C,
+    ) -> Result<u64, OutOfBoundsError> {
+        if let Some((first_direction, second_direction)) =
+            self.bounds.out_of_bounds_directions(coordinate)
+        {
+            return Err(OutOfBoundsError::new(
+                *coordinate.position(),
+                first_direction,
+                second_direction,
+            ));
+        }
+
+        let row = self
+            .bounds
+            .y_max_boundary()
+            .abs_diff(coordinate.y_coordinate());
+        let column = coordinate
+            .x_coordinate()
+            .abs_diff(self.bounds.x_min_boundary());
+        Ok(self.row_offsets[row as usize] + u64::from(column))
+    }
+
+    pub(super) fn set_occupied(&mut self, index: u64, occupied: bool) {
+        let word = &mut self.occupancy[(index / WORD_BITS) as usize];
+        if occupied {
+            *word |= 1 << (index % WORD_BITS);
+        } else {
+            *word &= !(1 << (index % WORD_BITS));
+        }
+    }
+
+    /// The occupied indices in increasing order. Empty stretches are skipped a word at a time.
+    pub(super) fn occupied_indices(&self) -> impl Iterator<Item = u64> + '_ {
+        self.occupancy
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| **word != 0)
+            .flat_map(|(word_index, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        None
+                    } else {
+                        let bit = u64::from(word.trailing_zeros());
+                        word &= word - 1;
+                        Some(word_index as u64 * WORD_BITS + bit)
+                    }
+                })
+            })
+    }
+
+    /// Whether any coordinate in the row is occupied.
+    pub(super) fn row_is_occupied(&self, y: i32) -> bool {
+        let row = self.bounds.y_max_boundary().abs_diff(y) as usize;
+        let start = self.row_offsets[row];
+        let end = start + self.bounds.x_count().as_u64();
+        self.any_occupied_in(start, end)
+    }
+
+    /// Whether any index in `start..end` is occupied.
+    fn any_occupied_in(&self, start: u64, end: u64) -> bool {
+        let mut index = start;
+        while index < end {
+            let word = self.occupancy[(index / WORD_BITS) as usize];
+            let offset = index % WORD_BITS;
+            let bits_in_word = (WORD_BITS - offset).min(end - index);
+            let mask = if bits_in_word == WORD_BITS {
+                u64::MAX
+            } else {
+                ((1 << bits_in_word) - 1) << offset
+            };
+
+            if word & mask != 0 {
+                return true;
+            }
+            index += bits_in_word;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxisCount;
+
+    fn cache(x_count: u64, y_count: u64, occupied: &[u64]) -> SpeedCache {
+        let bounds = OriginCenteredBounds::new(
+            AxisCount::from_u64_unchecked(x_count),
+            AxisCount::from_u64_unchecked(y_count),
+        );
+        SpeedCache::new(bounds, |index| occupied.contains(&index))
+    }
+
+    #[test]
+    fn coordinate_to_index_matches_bounded() {
+        let cache = cache(7, 4, &[]);
+        for (index, coordinate) in cache.coordinates().iter().enumerate() {
+            assert_eq!(cache.coordinate_to_index(coordinate), Ok(index as u64));
+            assert_eq!(
+                cache.bounds.coordinate_to_index(coordinate),
+                Ok(index as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn occupied_indices_across_words() {
+        let occupied = [0, 63, 64, 65, 199];
+        let cache = cache(20, 10, &occupied);
+        assert_eq!(cache.occupied_indices().collect::<Vec<u64>>(), occupied);
+    }
+
+    #[test]
+    fn set_occupied_updates_bitmap() {
+        let mut cache = cache(20, 10, &[5]);
+        cache.set_occupied(130, true);
+        cache.set_occupied(5, false);
+        assert_eq!(cache.occupied_indices().collect::<Vec<u64>>(), [130]);
+    }
+
+    #[test]
+    fn row_is_occupied_spanning_words() {
+        // Rows of 50 coordinates; the third row (indices 100 to 149) spans two words.
+        let cache = cache(50, 6, &[60, 120]);
+        let y_max = cache.bounds.y_max_boundary();
+        assert!(!cache.row_is_occupied(y_max));
+        assert!(cache.row_is_occupied(y_max - 1));
+        assert!(cache.row_is_occupied(y_max - 2));
+        assert!(!cache.row_is_occupied(y_max - 3));
+    }
+}
@@ -0,0 +1,370 @@
+use super::Grid;
+use crate::AbsoluteDirection;
+use crate::AxisCount;
+use crate::BoundedMovingObject;
+use crate::Coordinate;
+use crate::GridError;
+use crate::OutOfBoundsError;
+use crate::Positioned;
+use crate::WrapMode;
+use crate::bounded::Bounded;
+use std::fmt;
+use std::ops::Deref;
+
+/// A grid that holds an ordered stack of elements at each coordinate, for instance a pile of items
+/// or several units in the same place.
+///
+/// The stacks are stored in a [`Grid`] of vectors, with the top of a stack at the end of its
+/// vector. A coordinate is occupied in the grid exactly when its stack is not empty. A stack grid
+/// dereferences to this grid for read-only access, so all bounds and iteration methods are
+/// available.
+///
+/// An optional capacity limits the number of elements in each stack.
+///
+/// # Examples
+/// ```
+/// use tudi::AbsoluteDirection;
+/// use tudi::AxisCount;
+/// use tudi::Coordinate;
+/// use tudi::StackGrid;
+/// let count = AxisCount::try_from(3).unwrap();
+/// let mut floor = StackGrid::with_count(count, count).with_capacity(2);
+/// let origin = Coordinate::default();
+/// floor.push_element(&origin, "sword").unwrap();
+/// floor.push_element(&origin, "shield").unwrap();
+/// assert!(floor.push_element(&origin, "potion").is_err());
+///
+/// // Move the sword, which is at the bottom of the stack, one step east.
+/// let east = floor.move_element_in_direction(&origin, 0, AbsoluteDirection::East).unwrap();
+/// assert_eq!(floor.elements_at(&origin), Ok(&["shield"][..]));
+/// assert_eq!(floor.elements_at(&east), Ok(&["sword"][..]));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StackGrid<T> {
+    grid: Grid<Vec<T>>,
+    capacity: Option<usize>,
+}
+
+impl<T> StackGrid<T> {
+    /// A stack grid with empty stacks and no capacity.
+    pub fn with_count(x_count: AxisCount, y_count: AxisCount) -> Self {
+        Self::from(Grid::with_count(x_count, y_count))
+    }
+
+    /// Limit the number of elements in each stack.
+    ///
+    /// The limit only applies to elements that are added later; stacks that are already larger
+    /// are not truncated.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// The maximum number of elements in each stack, or `None` if the stacks are unlimited.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// The elements at a coordinate, from the bottom to the top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds.
+    pub fn elements_at<C: Positioned>(&self, coordinate: &C) -> Result<&[T], GridError> {
+        match self.grid.element(coordinate) {
+            Ok(stack) => Ok(stack),
+            Err(GridError::UnoccupiedError(_)) => Ok(&[]),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Put an element on top of the stack at a coordinate.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds.
+    ///
+    /// This method returns a [`GridError::CollisionError`] if the stack is full.
+    pub fn push_element<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        element: T,
+    ) -> Result<(), GridError> {
+        if self.is_full(coordinate)? {
+            return Err(GridError::CollisionError);
+        }
+
+        match self.grid.get_mut_element(coordinate) {
+            Ok(stack) => stack.push(element),
+            Err(_) => {
+                self.grid.store_element(coordinate, vec![element])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Take the element on top of the stack at a coordinate.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the coordinate is out of bounds.
+    ///
+    /// This method returns an error if the stack is empty.
+    pub fn pop_element<C: Positioned>(&mut self, coordinate: &C) -> Result<T, GridError> {
+        let len = self.elements_at(coordinate)?.len();
+        match len.checked_sub(1) {
+            Some(top) => self.remove_from_stack(coordinate, top),
+            None => Err(GridError::UnoccupiedError(*coordinate.position())),
+        }
+    }
+
+    /// Move one element out of the stack at a coordinate onto the top of the stack in a direction.
+    ///
+    /// The element is chosen by its index in the stack, counted from the bottom. The elements
+    /// above it move down one place. Returns the coordinate that the element moved to.
+    ///
+    /// In a [toroidal](WrapMode::Toroidal) grid that is a single coordinate wide along the
+    /// direction, the element wraps around onto the top of its own stack.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the resulting move would be out of bounds.
+    ///
+    /// This method returns a [`GridError::CollisionError`] if the stack in the direction is full.
+    ///
+    /// This method returns an error if the stack does not have an element at the index.
+    pub fn move_element_in_direction(
+        &mut self,
+        coordinate: &Coordinate,
+        index: usize,
+        direction: AbsoluteDirection,
+    ) -> Result<Coordinate, GridError> {
+        let coordinate = self.resolve(coordinate);
+        if index >= self.elements_at(&coordinate)?.len() {
+            return Err(GridError::UnoccupiedError(coordinate));
+        }
+
+        let mut marker = BoundedMovingObject::try_from((&self.grid, coordinate))?;
+        let target = if marker.move_in_absolute_direction_with(direction, 1, self.grid.wrap_mode())
+        {
+            *marker.position()
+        } else if self.grid.wrap_mode() == WrapMode::Toroidal {
+            coordinate
+        } else {
+            return Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                marker.coordinate_in_direction(direction, 1),
+                direction,
+                None,
+            )));
+        };
+
+        if target != coordinate && self.is_full(&target)? {
+            return Err(GridError::CollisionError);
+        }
+        let element = self.remove_from_stack(&coordinate, index)?;
+        self.push_element(&target, element)?;
+        Ok(target)
+    }
+
+    /// The grid of stacks. Empty stacks are unoccupied coordinates.
+    pub fn into_grid(self) -> Grid<Vec<T>> {
+        self.grid
+    }
+
+    /// Whether the stack at a coordinate has reached the capacity.
+    fn is_full<C: Positioned>(&self, coordinate: &C) -> Result<bool, GridError> {
+        let len = self.elements_at(coordinate)?.len();
+        Ok(self.capacity.is_some_and(|capacity| len >= capacity))
+    }
+
+    /// Remove the element at an index of a stack that has it, and remove the stack if it becomes
+    /// empty.
+    fn remove_from_stack<C: Positioned>(
+        &mut self,
+        coordinate: &C,
+        index: usize,
+    ) -> Result<T, GridError> {
+        let stack = self.grid.get_mut_element(coordinate)?;
+        let element = stack.remove(index);
+        if stack.is_empty() {
+            self.grid.remove_element(coordinate)?;
+        }
+        Ok(element)
+    }
+
+    /// The coordinate that a position refers to: the wrapped coordinate for a toroidal grid, and
+    /// the position itself otherwise.
+    fn resolve(&self, coordinate: &Coordinate) -> Coordinate {
+        match self.grid.wrap_mode() {
+            WrapMode::Bounded => *coordinate,
+            WrapMode::Toroidal => self.grid.wrap(coordinate),
+        }
+    }
+}
+
+impl<T> Default for StackGrid<T> {
+    /// A stack grid with a single coordinate at the origin and no capacity.
+    fn default() -> Self {
+        Self::from(Grid::default())
+    }
+}
+
+impl<T> From<Grid<Vec<T>>> for StackGrid<T> {
+    /// A stack grid with the stacks of a grid and no capacity. Empty vectors in the grid become
+    /// unoccupied coordinates.
+    fn from(mut grid: Grid<Vec<T>>) -> Self {
+        let empty = grid
+            .iter_elements_new()
+            .filter(|(_, stack)| stack.is_empty())
+            .map(|(coordinate, _)| coordinate)
+            .collect::<Vec<_>>();
+        for coordinate in empty {
+            grid.remove_element(&coordinate)
+                .expect("the coordinate has an empty stack");
+        }
+
+        Self {
+            grid,
+            capacity: None,
+        }
+    }
+}
+
+impl<T> Deref for StackGrid<T> {
+    type Target = Grid<Vec<T>>;
+
+    fn deref(&self) -> &Grid<Vec<T>> {
+        &self.grid
+    }
+}
+
+impl<T> fmt::Debug for StackGrid<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn floor() -> StackGrid<char> {
+        let count = AxisCount::try_from(3).unwrap();
+        StackGrid::with_count(count, count)
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let mut grid = floor();
+        let origin = Coordinate::default();
+        assert_eq!(grid.elements_at(&origin), Ok(&[][..]));
+        assert_eq!(
+            grid.pop_element(&origin),
+            Err(GridError::UnoccupiedError(origin))
+        );
+
+        grid.push_element(&origin, 'a').unwrap();
+        grid.push_element(&origin, 'b').unwrap();
+        assert_eq!(grid.elements_at(&origin), Ok(&['a', 'b'][..]));
+        assert_eq!(grid.element_statuses(), "...\n.#.\n...");
+
+        assert_eq!(grid.pop_element(&origin), Ok('b'));
+        assert_eq!(grid.pop_element(&origin), Ok('a'));
+        assert_eq!(grid.element_statuses(), "...\n...\n...");
+        assert!(grid.push_element(&Coordinate { x: 2, y: 0 }, 'c').is_err());
+        assert!(grid.elements_at(&Coordinate { x: 2, y: 0 }).is_err());
+    }
+
+    #[test]
+    fn capacity() {
+        let mut grid = floor().with_capacity(2);
+        let origin = Coordinate::default();
+        let east = Coordinate { x: 1, y: 0 };
+        grid.push_element(&origin, 'a').unwrap();
+        grid.push_element(&origin, 'b').unwrap();
+        assert_eq!(
+            grid.push_element(&origin, 'c'),
+            Err(GridError::CollisionError)
+        );
+
+        grid.push_element(&east, 'd').unwrap();
+        grid.push_element(&east, 'e').unwrap();
+        assert_eq!(
+            grid.move_element_in_direction(&origin, 1, AbsoluteDirection::East),
+            Err(GridError::CollisionError)
+        );
+        assert_eq!(grid.elements_at(&origin), Ok(&['a', 'b'][..]));
+        assert_eq!(grid.capacity(), Some(2));
+    }
+
+    #[test]
+    fn moving_an_element_out_of_a_stack() {
+        let mut grid = floor();
+        let origin = Coordinate::default();
+        for element in ['a', 'b', 'c'] {
+            grid.push_element(&origin, element).unwrap();
+        }
+
+        assert_eq!(
+            grid.move_element_in_direction(&origin, 1, AbsoluteDirection::North),
+            Ok(Coordinate { x: 0, y: 1 })
+        );
+        assert_eq!(grid.elements_at(&origin), Ok(&['a', 'c'][..]));
+        assert_eq!(
+            grid.move_element_in_direction(&origin, 2, AbsoluteDirection::North),
+            Err(GridError::UnoccupiedError(origin))
+        );
+        assert_eq!(
+            grid.move_element_in_direction(&Coordinate { x: 0, y: 1 }, 0, AbsoluteDirection::North),
+            Err(GridError::OutOfBoundsError(OutOfBoundsError::new(
+                Coordinate { x: 0, y: 2 },
+                AbsoluteDirection::North,
+                None
+            )))
+        );
+
+        grid.move_element_in_direction(&origin, 0, AbsoluteDirection::North)
+            .unwrap();
+        grid.move_element_in_direction(&origin, 0, AbsoluteDirection::North)
+            .unwrap();
+        assert_eq!(
+            grid.elements_at(&Coordinate { x: 0, y: 1 }),
+            Ok(&['b', 'a', 'c'][..])
+        );
+        assert_eq!(grid.element_statuses(), ".#.\n...\n...");
+    }
+
+    #[test]
+    fn toroidal_moves() {
+        let grid = Grid::with_count(
+            AxisCount::try_from(1).unwrap(),
+            AxisCount::try_from(3).unwrap(),
+        )
+        .with_wrap_mode(WrapMode::Toroidal);
+        let mut grid = StackGrid::from(grid).with_capacity(2);
+        let origin = Coordinate::default();
+        grid.push_element(&origin, 'a').unwrap();
+        grid.push_element(&origin, 'b').unwrap();
+
+        // A full stack can move an element onto itself.
+        assert_eq!(
+            grid.move_element_in_direction(&origin, 0, AbsoluteDirection::East),
+            Ok(origin)
+        );
+        assert_eq!(grid.elements_at(&origin), Ok(&['b', 'a'][..]));
+        assert_eq!(
+            grid.move_element_in_direction(&Coordinate { x: 0, y: 3 }, 0, AbsoluteDirection::South),
+            Ok(Coordinate { x: 0, y: -1 })
+        );
+    }
+
+    #[test]
+    fn empty_stacks_are_unoccupied() {
+        let mut grid = Grid::default();
+        grid.store_element(&Coordinate::default(), Vec::<u8>::new())
+            .unwrap();
+        let grid = StackGrid::from(grid);
+        assert_eq!(grid.iter_elements_new().count(), 0);
+        assert_eq!(grid.into_grid().iter_elements_new().count(), 0);
+    }
+}
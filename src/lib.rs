@@ -2,31 +2,61 @@ mod axis_count;
 pub mod bounded;
 mod bounded_moving_object;
 mod bounds;
+mod connectivity;
 mod coordinate;
 mod direction;
+mod distance_metric;
 mod dynamically_bounded;
 mod grid;
+mod line;
 mod mover;
 mod moving_object;
 mod origin_centered_bounds;
 mod out_of_bounds_error;
+pub mod pathfinding;
 mod positioned;
+mod screen_coordinate;
+mod wrap_mode;
 pub use crate::axis_count::AxisCount;
 pub use crate::axis_count::AxisCountError;
 pub use crate::bounded::Bounded;
 pub use crate::bounded_moving_object::BoundedMovingObject;
 pub use crate::bounds::Bounds;
+pub use crate::connectivity::Connectivity;
 pub use crate::coordinate::Coordinate;
 pub use crate::direction::AbsoluteDirection;
 pub use crate::direction::HorizontalDirection;
 pub use crate::direction::RelativeDirection;
 pub use crate::direction::VerticalDirection;
+pub use crate::distance_metric::DistanceMetric;
 pub use crate::dynamically_bounded::DynamicallyBounded;
+pub use crate::grid::Component;
+pub use crate::grid::Cycle;
 pub use crate::grid::Grid;
 pub use crate::grid::GridCreationError;
 pub use crate::grid::GridError;
+pub use crate::grid::GridIntoIter;
+pub use crate::grid::GridIter;
+pub use crate::grid::GridIterMut;
+pub use crate::grid::GridView;
+pub use crate::grid::GridViewMut;
+pub use crate::grid::GrowingGrid;
+pub use crate::grid::LayerMut;
+pub use crate::grid::LayerTransform;
+pub use crate::grid::LayeredGrid;
+pub use crate::grid::Layers;
+pub use crate::grid::LifeRule;
+pub use crate::grid::LifeRuleError;
+pub use crate::grid::OffsetGrid;
+pub use crate::grid::PerformanceTuning;
+pub use crate::grid::Rule;
+pub use crate::grid::Simulation;
+pub use crate::grid::StackGrid;
+pub use crate::line::Line;
 pub use crate::mover::Mover;
 pub use crate::moving_object::MovingObject;
 pub use crate::origin_centered_bounds::OriginCenteredBounds;
 pub use crate::out_of_bounds_error::OutOfBoundsError;
 pub use crate::positioned::Positioned;
+pub use crate::screen_coordinate::ScreenCoordinate;
+pub use crate::wrap_mode::WrapMode;
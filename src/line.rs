@@ -0,0 +1,351 @@
+use crate::Coordinate;
+use crate::bounded::Bounded;
+use std::iter::FusedIterator;
+
+/// The coordinates on a straight line between two coordinates, both included, found with
+/// Bresenham's line algorithm.
+///
+/// Consecutive coordinates are adjacent to each other, including diagonally, and the line has one
+/// coordinate for each step along its longer axis. Lines between the same two coordinates can
+/// differ depending on the direction they are walked in.
+///
+/// # Examples
+/// ```
+/// use tudi::Coordinate;
+/// use tudi::Line;
+/// let line = Line::new(Coordinate { x: 0, y: 0 }, Coordinate { x: 4, y: 2 });
+/// assert_eq!(
+///     line.collect::<Vec<_>>(),
+///     [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)].map(|(x, y)| Coordinate { x, y })
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Line {
+    start: Coordinate,
+    /// The next coordinate, or `None` once the end has been yielded.
+    next: Option<Coordinate>,
+    end: Coordinate,
+    x_distance: i64,
+    y_distance: i64,
+    x_step: i32,
+    y_step: i32,
+    error: i64,
+}
+
+impl Line {
+    pub fn new(start: Coordinate, end: Coordinate) -> Self {
+        let x_distance = i64::from(start.x.abs_diff(end.x));
+        let y_distance = -i64::from(start.y.abs_diff(end.y));
+        Self {
+            start,
+            next: Some(start),
+            end,
+            x_distance,
+            y_distance,
+            x_step: if start.x < end.x { 1 } else { -1 },
+            y_step: if start.y < end.y { 1 } else { -1 },
+            error: x_distance + y_distance,
+        }
+    }
+
+    /// The part of the line from its first to its last coordinate within the bounds, which is
+    /// empty if no coordinate of the line is within the bounds.
+    ///
+    /// Like the Liang–Barsky algorithm, the range of steps that stay within the bounds is narrowed
+    /// down one axis at a time, so coordinates outside of the bounds are never visited.
+    pub(crate) fn clip<B: Bounded>(mut self, bounds: &B) -> Self {
+        let Some(next) = self.next else {
+            return self;
+        };
+
+        let axes = [
+            (
+                (|coordinate: Coordinate| coordinate.x) as fn(_) -> _,
+                bounds.x_min_boundary(),
+                bounds.x_max_boundary(),
+            ),
+            (
+                (|coordinate: Coordinate| coordinate.y) as fn(_) -> _,
+                bounds.y_min_boundary(),
+                bounds.y_max_boundary(),
+            ),
+        ];
+        let (mut first, mut end) = (self.step_of(next), self.step_of(self.end) + 1);
+        for (axis, min, max) in axes {
+            let at = |step| axis(self.state_at(step).0);
+            let increasing = axis(self.start) <= axis(self.end);
+            first = partition_point(first, end, |step| {
+                if increasing {
+                    at(step) < min
+                } else {
+                    at(step) > max
+                }
+            });
+            end = partition_point(first, end, |step| {
+                if increasing {
+                    at(step) <= max
+                } else {
+                    at(step) >= min
+                }
+            });
+        }
+
+        if first < end {
+            self.end = self.state_at(end - 1).0;
+            let (next, error) = self.state_at(first);
+            self.next = Some(next);
+            self.error = error;
+        } else {
+            self.next = None;
+        }
+        self
+    }
+
+    /// The number of steps from the start of the line to one of its coordinates. Every step moves
+    /// one coordinate along the longer axis of the line.
+    fn step_of(&self, coordinate: Coordinate) -> u64 {
+        u64::from(
+            self.start
+                .x
+                .abs_diff(coordinate.x)
+                .max(self.start.y.abs_diff(coordinate.y)),
+        )
+    }
+
+    /// The coordinate and the error of the line after a number of steps from its start.
+    fn state_at(&self, step: u64) -> (Coordinate, i64) {
+        let (x_distance, y_distance) = (i128::from(self.x_distance), -i128::from(self.y_distance));
+        let step = i128::from(step);
+        let (x_steps, y_steps) = if x_distance >= y_distance {
+            let y_steps = if x_distance == 0 {
+                0
+            } else {
+                (2 * y_distance * step + x_distance) / (2 * x_distance)
+            };
+            (step, y_steps)
+        } else {
+            (
+                (2 * x_distance * step + y_distance) / (2 * y_distance),
+                step,
+            )
+        };
+
+        let offset = |value: i32, axis_step: i32, steps: i128| {
+            i32::try_from(i128::from(value) + i128::from(axis_step) * steps)
+                .expect("the coordinate is on the line")
+        };
+        let coordinate = Coordinate {
+            x: offset(self.start.x, self.x_step, x_steps),
+            y: offset(self.start.y, self.y_step, y_steps),
+        };
+        let error = x_distance * (1 + y_steps) - y_distance * (1 + x_steps);
+        (
+            coordinate,
+            i64::try_from(error).expect("the error is bounded by the distances"),
+        )
+    }
+}
+
+/// The first number in `low..high` for which the predicate is false, given that the predicate is
+/// true for all numbers before it and false for all numbers after it.
+fn partition_point(mut low: u64, mut high: u64, predicate: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+impl Iterator for Line {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        if current == self.end {
+            self.next = None;
+            return Some(current);
+        }
+
+        let mut next = current;
+        let doubled_error = 2 * self.error;
+        if doubled_error >= self.y_distance {
+            self.error += self.y_distance;
+            next.x += self.x_step;
+        }
+        if doubled_error <= self.x_distance {
+            self.error += self.x_distance;
+            next.y += self.y_step;
+        }
+        self.next = Some(next);
+        Some(current)
+    }
+
+    /// Skips ahead without visiting the coordinates in between.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let step = self.step_of(self.next?).saturating_add(n as u64);
+        if step > self.step_of(self.end) {
+            self.next = None;
+            return None;
+        }
+
+        let (next, error) = self.state_at(step);
+        self.next = Some(next);
+        self.error = error;
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.next.map_or(0, |next| {
+            let steps = next.x.abs_diff(self.end.x).max(next.y.abs_diff(self.end.y));
+            steps as usize + 1
+        });
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Line {}
+
+impl FusedIterator for Line {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bounds;
+    use crate::Connectivity;
+
+    fn line(start: (i32, i32), end: (i32, i32)) -> Vec<(i32, i32)> {
+        Line::new(
+            Coordinate {
+                x: start.0,
+                y: start.1,
+            },
+            Coordinate { x: end.0, y: end.1 },
+        )
+        .map(|coordinate| (coordinate.x, coordinate.y))
+        .collect()
+    }
+
+    #[test]
+    fn single_coordinate() {
+        assert_eq!(line((3, -2), (3, -2)), [(3, -2)]);
+    }
+
+    #[test]
+    fn straight_lines() {
+        assert_eq!(line((0, 0), (0, 3)), [(0, 0), (0, 1), (0, 2), (0, 3)]);
+        assert_eq!(line((0, 0), (-2, 0)), [(0, 0), (-1, 0), (-2, 0)]);
+        assert_eq!(line((1, 1), (-1, -1)), [(1, 1), (0, 0), (-1, -1)]);
+    }
+
+    #[test]
+    fn steep_line() {
+        assert_eq!(
+            line((0, 0), (-2, -5)),
+            [(0, 0), (0, -1), (-1, -2), (-1, -3), (-2, -4), (-2, -5)]
+        );
+    }
+
+    #[test]
+    fn lines_in_every_octant_are_connected() {
+        let start = Coordinate { x: 1, y: -1 };
+        for (x, y) in [
+            (7, 3),
+            (3, 7),
+            (-3, 7),
+            (-7, 3),
+            (-7, -3),
+            (-3, -7),
+            (3, -7),
+            (7, -3),
+        ] {
+            let end = Coordinate { x, y };
+            let coordinates = Line::new(start, end).collect::<Vec<_>>();
+            assert_eq!(coordinates.first(), Some(&start));
+            assert_eq!(coordinates.last(), Some(&end));
+            assert_eq!(
+                coordinates.len() as u64,
+                Connectivity::Euclid.distance(&start, &end) + 1
+            );
+            for step in coordinates.windows(2) {
+                assert_eq!(Connectivity::Euclid.distance(&step[0], &step[1]), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn nth_skips_to_the_same_coordinates() {
+        let start = Coordinate { x: 1, y: -1 };
+        for x in -9..=9 {
+            for y in -9..=9 {
+                let end = Coordinate { x, y };
+                let coordinates = Line::new(start, end).collect::<Vec<_>>();
+                for n in 0..=coordinates.len() {
+                    let mut line = Line::new(start, end);
+                    assert_eq!(line.nth(n), coordinates.get(n).copied());
+                    assert!(line.eq(coordinates.iter().skip(n + 1).copied()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_lines_keep_their_coordinates() {
+        let bounds = Bounds::from_boundaries(-2, 3, -1, 2);
+        for (start, end) in [
+            ((-9, -9), (9, 9)),
+            ((-9, 7), (9, -4)),
+            ((0, 0), (2, 1)),
+            ((-5, 1), (-3, 8)),
+            ((4, -8), (-1, 13)),
+            ((6, 0), (9, 2)),
+        ] {
+            let start = Coordinate {
+                x: start.0,
+                y: start.1,
+            };
+            let end = Coordinate { x: end.0, y: end.1 };
+            let expected = Line::new(start, end)
+                .filter(|coordinate| bounds.is_within_bounds(coordinate))
+                .collect::<Vec<_>>();
+            assert!(Line::new(start, end).clip(&bounds).eq(expected));
+        }
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut line = Line::new(Coordinate { x: 0, y: 0 }, Coordinate { x: 5, y: -3 });
+        for remaining in (0..=6).rev() {
+            assert_eq!(line.len(), remaining);
+            line.next();
+        }
+        assert_eq!(line.next(), None);
+    }
+
+    #[test]
+    fn extreme_coordinates() {
+        let start = Coordinate {
+            x: i32::MIN,
+            y: i32::MAX,
+        };
+        let end = Coordinate {
+            x: i32::MAX,
+            y: i32::MIN,
+        };
+        let mut line = Line::new(start, end);
+        assert_eq!(line.len(), u32::MAX as usize + 1);
+        assert_eq!(line.next(), Some(start));
+        assert_eq!(
+            line.next(),
+            Some(Coordinate {
+                x: i32::MIN + 1,
+                y: i32::MAX - 1
+            })
+        );
+        assert_eq!(line.nth(u32::MAX as usize - 2), Some(end));
+        assert_eq!(line.next(), None);
+    }
+}
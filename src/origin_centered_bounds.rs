@@ -68,6 +68,38 @@ impl OriginCenteredBounds {
         }
     }
 
+    /// Shrink the bounds by one, undoing [`Self::expand_bounds_horizontally`]. Returns true if the
+    /// bounds are shrunk from the east and false if shrunk from the west, or `None` if the bounds
+    /// only have one column.
+    pub fn shrink_bounds_horizontally(&mut self) -> Option<bool> {
+        let x_count = OriginBounded::x_count(&self).as_u64();
+        if x_count == 1 {
+            None
+        } else if x_count.is_multiple_of(2) {
+            self.0.shrink_in_direction(AbsoluteDirection::East);
+            Some(true)
+        } else {
+            self.0.shrink_in_direction(AbsoluteDirection::West);
+            Some(false)
+        }
+    }
+
+    /// Shrink the bounds by one, undoing [`Self::expand_bounds_vertically`]. Returns true if the
+    /// bounds are shrunk from the north and false if shrunk from the south, or `None` if the bounds
+    /// only have one row.
+    pub fn shrink_bounds_vertically(&mut self) -> Option<bool> {
+        let y_count = OriginBounded::y_count(&self).as_u64();
+        if y_count == 1 {
+            None
+        } else if y_count.is_multiple_of(2) {
+            self.0.shrink_in_direction(AbsoluteDirection::North);
+            Some(true)
+        } else {
+            self.0.shrink_in_direction(AbsoluteDirection::South);
+            Some(false)
+        }
+    }
+
     pub fn x_count(&self) -> AxisCount {
         OriginBounded::x_count(self)
     }
@@ -210,6 +242,28 @@ pub mod tests {
         assert_eq!(bounds.x_min_boundary(), -1);
     }
 
+    #[test]
+    fn shrinking_undoes_expansion() {
+        let mut bounds = create_smallest();
+        let mut history = vec![bounds];
+        for _ in 0..5 {
+            bounds.expand_bounds_vertically();
+            bounds.expand_bounds_horizontally();
+            history.push(bounds);
+        }
+
+        history.pop();
+        while let Some(previous) = history.pop() {
+            assert!(bounds.shrink_bounds_vertically().is_some());
+            assert!(bounds.shrink_bounds_horizontally().is_some());
+            assert_eq!(bounds, previous);
+        }
+
+        assert_eq!(bounds.shrink_bounds_vertically(), None);
+        assert_eq!(bounds.shrink_bounds_horizontally(), None);
+        assert_eq!(bounds, create_smallest());
+    }
+
     #[test]
     fn test_row_expansion() {
         let mut bounds = create_smallest();
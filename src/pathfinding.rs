@@ -0,0 +1,513 @@
+//! Shortest paths over a [`Grid`].
+//!
+//! Every search moves between adjacent coordinates according to a [`Connectivity`] and never
+//! leaves the bounds of the grid. On a [toroidal](crate::WrapMode::Toroidal) grid, searches wrap
+//! around the borders, see [`Grid::neighbors`]. Whether a coordinate can be entered, and at which cost, is
+//! decided by a closure that receives the coordinate and its element, if any. The start of a
+//! search is never checked; it is always part of the path.
+//!
+//! - [`bfs`] and [`bfs_distances`] count steps.
+//! - [`dijkstra`] and [`dijkstra_distances`] add up the cost of entering each coordinate.
+//! - [`astar`] also adds up costs, but uses a heuristic to look at fewer coordinates.
+//!
+//! # Examples
+//! ```
+//! use std::collections::HashMap;
+//! use tudi::Connectivity;
+//! use tudi::Coordinate;
+//! use tudi::Grid;
+//! use tudi::pathfinding;
+//!
+//! let walls = HashMap::from([('#', ())]);
+//! let maze = Grid::from_str_by_map("...\n##.\n...", &walls).unwrap();
+//!
+//! let (path, steps) = pathfinding::bfs(
+//!     &maze,
+//!     Coordinate { x: -1, y: 1 },
+//!     Coordinate { x: -1, y: -1 },
+//!     Connectivity::Manhattan,
+//!     |_, wall| wall.is_none(),
+//! )
+//! .unwrap()
+//! .unwrap();
+//!
+//! assert_eq!(steps, 6);
+//! assert_eq!(path.len(), 7);
+//! ```
+use crate::Connectivity;
+use crate::Coordinate;
+use crate::Grid;
+use crate::OutOfBoundsError;
+use crate::bounded::Bounded;
+use crate::bounded::OriginBounded;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+/// The coordinates of a path from its start to its goal, both included, and its total cost.
+pub type Path = (Vec<Coordinate>, u64);
+
+/// The shortest path between two coordinates, counted in steps.
+///
+/// `passable` decides whether a coordinate can be entered.
+///
+/// # Errors
+///
+/// This function returns an error if the start or the goal is out of bounds.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    goal: Coordinate,
+    connectivity: Connectivity,
+    passable: impl FnMut(Coordinate, Option<&T>) -> bool,
+) -> Result<Option<Path>, OutOfBoundsError> {
+    grid.coordinate_to_index(&goal)?;
+    let search = breadth_first(grid, start, Some(goal), connectivity, passable)?;
+    Ok(search.path_to(goal))
+}
+
+/// The number of steps from the start to every reachable coordinate.
+///
+/// `passable` decides whether a coordinate can be entered. The returned grid has the bounds of
+/// the input grid and is empty at every coordinate that cannot be reached.
+///
+/// # Errors
+///
+/// This function returns an error if the start is out of bounds.
+pub fn bfs_distances<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    connectivity: Connectivity,
+    passable: impl FnMut(Coordinate, Option<&T>) -> bool,
+) -> Result<Grid<u64>, OutOfBoundsError> {
+    let search = breadth_first(grid, start, None, connectivity, passable)?;
+    Ok(search.into_distance_grid(grid))
+}
+
+/// The cheapest path between two coordinates.
+///
+/// `cost` returns the cost of entering a coordinate, or `None` if the coordinate cannot be
+/// entered.
+///
+/// # Errors
+///
+/// This function returns an error if the start or the goal is out of bounds.
+pub fn dijkstra<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    goal: Coordinate,
+    connectivity: Connectivity,
+    cost: impl FnMut(Coordinate, Option<&T>) -> Option<u64>,
+) -> Result<Option<Path>, OutOfBoundsError> {
+    astar(grid, start, goal, connectivity, cost, |_| 0)
+}
+
+/// The cost of the cheapest path from the start to every reachable coordinate.
+///
+/// `cost` returns the cost of entering a coordinate, or `None` if the coordinate cannot be
+/// entered. The returned grid has the bounds of the input grid and is empty at every coordinate
+/// that cannot be reached.
+///
+/// # Errors
+///
+/// This function returns an error if the start is out of bounds.
+pub fn dijkstra_distances<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    connectivity: Connectivity,
+    cost: impl FnMut(Coordinate, Option<&T>) -> Option<u64>,
+) -> Result<Grid<u64>, OutOfBoundsError> {
+    let search = best_first(grid, start, None, connectivity, cost, |_| 0)?;
+    Ok(search.into_distance_grid(grid))
+}
+
+/// The cheapest path between two coordinates, guided by a heuristic.
+///
+/// `cost` returns the cost of entering a coordinate, or `None` if the coordinate cannot be
+/// entered. `heuristic` estimates the cost from a coordinate to the goal. The path is only
+/// guaranteed to be the cheapest if the heuristic never overestimates that cost, and never drops
+/// by more than the cost of a single step. If every step costs at least one,
+/// [`Connectivity::distance`] to the goal is such a heuristic, unless the grid is
+/// [toroidal](crate::WrapMode::Toroidal) and the path can take a shortcut around the border.
+///
+/// # Errors
+///
+/// This function returns an error if the start or the goal is out of bounds.
+///
+/// # Examples
+/// ```
+/// use tudi::Connectivity;
+/// use tudi::Coordinate;
+/// use tudi::grid;
+/// use tudi::pathfinding;
+///
+/// let mut swamp = grid!(5, 5);
+/// swamp.store_element(&Coordinate { x: 0, y: 0 }, 10);
+///
+/// let goal = Coordinate { x: 2, y: 0 };
+/// let connectivity = Connectivity::Manhattan;
+/// let (path, cost) = pathfinding::astar(
+///     &swamp,
+///     Coordinate { x: -2, y: 0 },
+///     goal,
+///     connectivity,
+///     |_, swamp| Some(swamp.copied().unwrap_or(1)),
+///     |coordinate| connectivity.distance(&coordinate, &goal),
+/// )
+/// .unwrap()
+/// .unwrap();
+///
+/// // walking around the swamp is cheaper than walking through it.
+/// assert_eq!(cost, 6);
+/// assert!(!path.contains(&Coordinate { x: 0, y: 0 }));
+/// ```
+pub fn astar<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    goal: Coordinate,
+    connectivity: Connectivity,
+    cost: impl FnMut(Coordinate, Option<&T>) -> Option<u64>,
+    heuristic: impl FnMut(Coordinate) -> u64,
+) -> Result<Option<Path>, OutOfBoundsError> {
+    grid.coordinate_to_index(&goal)?;
+    let search = best_first(grid, start, Some(goal), connectivity, cost, heuristic)?;
+    Ok(search.path_to(goal))
+}
+
+/// The distances found by a search and the coordinate each coordinate was reached from.
+struct Search {
+    distances: HashMap<Coordinate, u64>,
+    came_from: HashMap<Coordinate, Coordinate>,
+}
+
+impl Search {
+    fn new(start: Coordinate) -> Self {
+        Self {
+            distances: HashMap::from([(start, 0)]),
+            came_from: HashMap::new(),
+        }
+    }
+
+    fn path_to(&self, goal: Coordinate) -> Option<Path> {
+        let cost = *self.distances.get(&goal)?;
+        let mut path = vec![goal];
+        while let Some(previous) = self
+            .came_from
+            .get(path.last().expect("the path is not empty"))
+        {
+            path.push(*previous);
+        }
+        path.reverse();
+        Some((path, cost))
+    }
+
+    fn into_distance_grid<T>(self, grid: &Grid<T>) -> Grid<u64> {
+        let mut result =
+            Grid::with_count(OriginBounded::x_count(grid), OriginBounded::y_count(grid))
+                .with_performance_tuning(grid.performance_tuning())
+                .with_wrap_mode(grid.wrap_mode());
+        for (coordinate, distance) in self.distances {
+            result
+                .store_element(&coordinate, distance)
+                .expect("searches stay within bounds");
+        }
+        result
+    }
+}
+
+fn breadth_first<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    goal: Option<Coordinate>,
+    connectivity: Connectivity,
+    mut passable: impl FnMut(Coordinate, Option<&T>) -> bool,
+) -> Result<Search, OutOfBoundsError> {
+    grid.coordinate_to_index(&start)?;
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        if Some(current) == goal {
+            break;
+        }
+
+        let distance = search.distances[&current] + 1;
+        for neighbor in grid.neighbors(&current, connectivity) {
+            if !search.distances.contains_key(&neighbor)
+                && passable(neighbor, grid.element_unchecked(&neighbor))
+            {
+                search.distances.insert(neighbor, distance);
+                search.came_from.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+fn best_first<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    goal: Option<Coordinate>,
+    connectivity: Connectivity,
+    mut cost: impl FnMut(Coordinate, Option<&T>) -> Option<u64>,
+    mut heuristic: impl FnMut(Coordinate) -> u64,
+) -> Result<Search, OutOfBoundsError> {
+    let index_of = |coordinate: &Coordinate| {
+        grid.coordinate_to_index(coordinate)
+            .expect("searches stay within bounds")
+    };
+
+    grid.coordinate_to_index(&start)?;
+    let mut search = Search::new(start);
+    // Ordered by estimated total cost, then by index to make the result deterministic.
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), index_of(&start), 0))]);
+
+    while let Some(Reverse((_, index, distance))) = queue.pop() {
+        let current = grid
+            .index_to_coordinate(index)
+            .expect("searches stay within bounds");
+        if distance > search.distances[&current] {
+            continue;
+        }
+        if Some(current) == goal {
+            break;
+        }
+
+        for neighbor in grid.neighbors(&current, connectivity) {
+            let Some(step) = cost(neighbor, grid.element_unchecked(&neighbor)) else {
+                continue;
+            };
+
+            let neighbor_distance = distance.saturating_add(step);
+            if search
+                .distances
+                .get(&neighbor)
+                .is_none_or(|known| neighbor_distance < *known)
+            {
+                search.distances.insert(neighbor, neighbor_distance);
+                search.came_from.insert(neighbor, current);
+                queue.push(Reverse((
+                    neighbor_distance.saturating_add(heuristic(neighbor)),
+                    index_of(&neighbor),
+                    neighbor_distance,
+                )));
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbsoluteDirection;
+    use crate::PerformanceTuning;
+    use std::collections::HashMap;
+
+    const MAZE: &str = "\
+.....#...
+.###.#.#.
+.#...#.#.
+.#.###.#.
+.#.....#.";
+
+    /// A 9x5 grid where '#' is a wall.
+    fn maze() -> Grid<char> {
+        Grid::from_str_by_map(MAZE, &HashMap::from([('#', '#')])).unwrap()
+    }
+
+    /// The coordinate of a character in [`MAZE`], counted from the northwest corner.
+    fn at(column: i32, row: i32) -> Coordinate {
+        Coordinate {
+            x: column - 4,
+            y: 2 - row,
+        }
+    }
+
+    fn is_open(_: Coordinate, element: Option<&char>) -> bool {
+        element.is_none()
+    }
+
+    fn assert_is_walkable(path: &[Coordinate], connectivity: Connectivity) {
+        let grid = maze();
+        for step in path.windows(2) {
+            assert_eq!(connectivity.distance(&step[0], &step[1]), 1);
+        }
+        assert!(
+            path.iter()
+                .all(|coordinate| grid.element(coordinate).is_err())
+        );
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let (path, steps) = bfs(
+            &maze(),
+            at(0, 0),
+            at(8, 4),
+            Connectivity::Manhattan,
+            is_open,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(steps, 24);
+        assert_eq!(path.len(), 25);
+        assert_eq!(path.first(), Some(&at(0, 0)));
+        assert_eq!(path.last(), Some(&at(8, 4)));
+        assert_is_walkable(&path, Connectivity::Manhattan);
+    }
+
+    #[test]
+    fn euclid_connectivity_cuts_corners() {
+        let (path, steps) = bfs(&maze(), at(0, 0), at(8, 4), Connectivity::Euclid, is_open)
+            .unwrap()
+            .unwrap();
+        assert_eq!(steps, 17);
+        assert_is_walkable(&path, Connectivity::Euclid);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let mut grid = maze();
+        grid.store_element(&at(4, 0), '#').unwrap();
+        assert_eq!(
+            bfs(&grid, at(0, 0), at(8, 4), Connectivity::Manhattan, is_open),
+            Ok(None)
+        );
+        assert_eq!(
+            dijkstra(
+                &grid,
+                at(0, 0),
+                at(8, 4),
+                Connectivity::Manhattan,
+                |_, wall| { wall.is_none().then_some(1) }
+            ),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn path_to_start() {
+        assert_eq!(
+            bfs(
+                &maze(),
+                at(0, 0),
+                at(0, 0),
+                Connectivity::Manhattan,
+                is_open
+            ),
+            Ok(Some((vec![at(0, 0)], 0)))
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_endpoints() {
+        let outside = Coordinate { x: 5, y: 0 };
+        let error = OutOfBoundsError::new(outside, AbsoluteDirection::East, None);
+        assert_eq!(
+            bfs(&maze(), outside, at(0, 0), Connectivity::Manhattan, is_open),
+            Err(error.clone())
+        );
+        assert_eq!(
+            astar(
+                &maze(),
+                at(0, 0),
+                outside,
+                Connectivity::Manhattan,
+                |_, _| Some(1),
+                |_| 0
+            ),
+            Err(error)
+        );
+        assert!(bfs_distances(&maze(), outside, Connectivity::Manhattan, is_open).is_err());
+    }
+
+    #[test]
+    fn distance_maps_agree_for_unit_costs() {
+        for tuning in [PerformanceTuning::Memory, PerformanceTuning::Speed] {
+            let grid = maze().with_performance_tuning(tuning);
+            let steps = bfs_distances(&grid, at(0, 0), Connectivity::Manhattan, is_open).unwrap();
+            let costs = dijkstra_distances(&grid, at(0, 0), Connectivity::Manhattan, |_, wall| {
+                wall.is_none().then_some(1)
+            })
+            .unwrap();
+
+            assert_eq!(steps, costs);
+            assert_eq!(steps.bounds(), grid.bounds());
+            assert_eq!(steps.performance_tuning(), tuning);
+            assert_eq!(steps.element(&at(8, 4)), Ok(&24));
+            assert_eq!(steps.element(&at(2, 2)), Ok(&8));
+            // walls are never reached.
+            assert_eq!(
+                steps.iter_elements_new().count() + grid.iter_elements_new().count(),
+                45
+            );
+        }
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_coordinates() {
+        let mut grid = Grid::with_count(
+            crate::AxisCount::from_u64_unchecked(5),
+            crate::AxisCount::from_u64_unchecked(3),
+        );
+        for x in -1..=1 {
+            grid.store_element(&Coordinate { x, y: 0 }, 5).unwrap();
+        }
+        let cost = |_, element: Option<&u64>| Some(element.copied().unwrap_or(1));
+
+        let (path, total) = dijkstra(
+            &grid,
+            Coordinate { x: -2, y: 0 },
+            Coordinate { x: 2, y: 0 },
+            Connectivity::Manhattan,
+            cost,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(total, 6);
+        assert_eq!(path.len(), 7);
+
+        let distances = dijkstra_distances(
+            &grid,
+            Coordinate { x: -2, y: 0 },
+            Connectivity::Manhattan,
+            cost,
+        )
+        .unwrap();
+        assert_eq!(distances.element(&Coordinate { x: 0, y: 0 }), Ok(&8));
+        assert_eq!(distances.element(&Coordinate { x: 0, y: 1 }), Ok(&3));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let cost = |coordinate: Coordinate, wall: Option<&char>| {
+            wall.is_none()
+                .then_some(1 + u64::from(coordinate.x.unsigned_abs()))
+        };
+        for connectivity in [Connectivity::Manhattan, Connectivity::Euclid] {
+            for goal in [at(8, 4), at(2, 2), at(6, 0)] {
+                let expected = dijkstra(&maze(), at(0, 4), goal, connectivity, cost).unwrap();
+                let (path, total) = astar(&maze(), at(0, 4), goal, connectivity, cost, |c| {
+                    connectivity.distance(&c, &goal)
+                })
+                .unwrap()
+                .unwrap();
+
+                assert_eq!(Some(total), expected.map(|(_, total)| total));
+                assert_is_walkable(&path, connectivity);
+                assert_eq!(
+                    path.iter()
+                        .skip(1)
+                        .map(|coordinate| cost(*coordinate, None).unwrap())
+                        .sum::<u64>(),
+                    total
+                );
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::enum_glob_use)]
 use crate::AbsoluteDirection;
 use crate::Coordinate;
+use crate::Line;
 
 pub trait Positioned {
     fn position(&self) -> &Coordinate;
@@ -144,6 +145,21 @@ pub trait Positioned {
         (first_direction, second_direction)
     }
 
+    /// The coordinates on the straight line from self to a target, both included.
+    ///
+    /// See also [`Line`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tudi::Coordinate;
+    /// use tudi::Positioned;
+    /// let target = Coordinate { x: 3, y: 1 };
+    /// assert_eq!(Coordinate::default().line_to(&target).len(), 4);
+    /// ```
+    fn line_to<C: Positioned>(&self, target: &C) -> Line {
+        Line::new(*self.position(), *target.position())
+    }
+
     fn on_opposite_sides_of_row(&self, cord: &Self, row: &i32) -> bool
     where
         Self: Sized,
@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// A position in screen space: the column and row counted from the northwest corner of a bounded
+/// region, with rows growing downward like lines of text.
+///
+/// A [`Coordinate`](crate::Coordinate) has y growing north, while text input and terminal output
+/// have rows growing south. A screen coordinate only has a meaning within a bounded region; use
+/// [`Bounded::coordinate_to_screen`](crate::Bounded::coordinate_to_screen()) and
+/// [`Bounded::screen_to_coordinate`](crate::Bounded::screen_to_coordinate()) to convert between
+/// the two. The conversions are lossless for every coordinate within bounds. The elements of a
+/// grid can also be accessed at screen coordinates directly, see
+/// [`Grid::element_at_screen`](crate::Grid::element_at_screen()).
+///
+/// Screen coordinates are ordered by row, then by column, which is the order of characters in a
+/// string like the ones read by [`Grid::from_str_by_map`](crate::Grid::from_str_by_map()).
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use tudi::Bounded;
+/// use tudi::Grid;
+/// use tudi::ScreenCoordinate;
+/// let input = "#..\n..#";
+/// let grid = Grid::from_str_by_map(input, &HashMap::from([('#', ())])).unwrap();
+///
+/// // The '#' on the second line, at the third character.
+/// let screen = ScreenCoordinate { column: 2, row: 1 };
+/// let coordinate = grid.screen_to_coordinate(screen).unwrap();
+/// assert!(grid.element(&coordinate).is_ok());
+/// assert_eq!(grid.coordinate_to_screen(&coordinate), Ok(screen));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Default, Hash)]
+pub struct ScreenCoordinate {
+    pub row: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for ScreenCoordinate {
+    /// The column followed by the row, like `(column, row)`.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "({}, {})", self.column, self.row)
+    }
+}
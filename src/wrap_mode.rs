@@ -0,0 +1,11 @@
+/// What happens at the edges of a bounded region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WrapMode {
+    /// The edges are walls: coordinates past them are out of bounds.
+    #[default]
+    Bounded,
+
+    /// The region wraps around like a torus: stepping past one edge leads to the opposite edge.
+    /// See [`Bounded::wrap`](crate::Bounded::wrap()).
+    Toroidal,
+}